@@ -111,7 +111,7 @@ class Display:
 		pass
 	
 	def setInventory(self, items, selector):
-		itemStrs = ["{} {}".format(item, siCount(count)) for item, count in items]
+		itemStrs = ["{} {}{}".format(item, siCount(count), wearStr(wear)) for item, count, *wear in items]
 		inventory = self.getWidget("inventory")
 		inventory.set_items(itemStrs)
 		inventory.select(selector)
@@ -149,6 +149,12 @@ class Display:
 	def update_size(self):
 		self.screen.reset()
 
+def wearStr(wear):
+	if not wear or wear[0] is None:
+		return ""
+	durability, maximum = wear[0]
+	return " ({}/{})".format(durability, maximum)
+
 def siCount(count):
 	if count is None:
 		return ""
@@ -1,14 +1,18 @@
 
 use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use enum_assoc::Assoc;
 use crate::{
 	item::Item,
 	tile::{Structure, Ground},
-	timestamp::Timestamp,
+	timestamp::Duration,
 	worldmessages::SoundType,
+	recipe::RecipeId,
 	random
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all="snake_case")]
 pub enum InteractionType {
 	Take,
 	Smash,
@@ -18,20 +22,32 @@ pub enum InteractionType {
 	Fuel,
 	Fertilize,
 	BuildSaw,
+	Ignite,
+	Compost,
+	Cure,
+	Repel,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Assoc, Serialize, Deserialize)]
+#[serde(rename_all="snake_case")]
+#[func(pub fn duration(self) -> Duration {Duration(5)})]
 pub enum CraftType {
+	#[assoc(duration = Duration(20))]
 	Marker,
+	#[assoc(duration = Duration(2))]
 	Water,
+	#[assoc(duration = Duration(10))]
 	GardeningTable,
 	SawTable,
+	#[assoc(duration = Duration(10))]
+	Cooking,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 pub struct Interact {
 	typ: InteractionType,
 	level: u32,
+	#[serde(default)]
 	pub use_item: bool,
 	received: Option<Item>,
 }
@@ -43,10 +59,13 @@ impl Interact {
 }
 
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all="snake_case")]
 pub enum Action {
 	Interact(Interact),
 	Clear,
+	Dig,
+	Enrich,
 	BuildClaim(Structure),
 	Craft(CraftType, Item, HashMap<Item, usize>),
 	Build(Structure, HashMap<Item, usize>),
@@ -63,13 +82,19 @@ impl Action{
 }
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Interactable {
 	remains: Option<Structure>,
+	#[serde(default)]
 	items: Vec<Item>,
 	action_type: InteractionType,
+	#[serde(default)]
 	min_level: u32,
-	level_odds: Vec<f32>
+	#[serde(default)]
+	level_odds: Vec<f32>,
+	/// Items that are each given with their own odds
+	#[serde(default)]
+	extra_items: Vec<(Item, f32)>,
 }
 
 impl Interactable {
@@ -79,10 +104,38 @@ impl Interactable {
 			min_level,
 			level_odds: level_odds.to_vec(),
 			remains,
-			items: items.to_vec()
+			items: items.to_vec(),
+			extra_items: Vec::new(),
 		}
 	}
 	
+	pub fn with_extra(mut self, item: Item, odds: f32) -> Self {
+		self.extra_items.push((item, odds));
+		self
+	}
+	
+	pub fn leaving(mut self, remains: Structure) -> Self {
+		self.remains = Some(remains);
+		self
+	}
+	
+	/// Only half of the items are given and there is no chance of more
+	pub fn damaged(mut self) -> Self {
+		self.items.truncate(self.items.len().div_ceil(2));
+		self.extra_items.clear();
+		self
+	}
+	
+	/// Every level of bonus gives another chance to get each item again
+	pub fn with_bonus(mut self, bonus: u8) -> Self {
+		for _ in 0..bonus {
+			for item in self.items.clone() {
+				self.extra_items.push((item, 0.5));
+			}
+		}
+		self
+	}
+	
 	pub fn transform(action_type: InteractionType, min_level: u32, into: Structure) -> Self {
 		Self::new(action_type, min_level, &[], Some(into), &[])
 	}
@@ -95,7 +148,15 @@ impl Interactable {
 		Self::new(InteractionType::Take, 0, &[], Some(Structure::Air), items)
 	}
 	
-	pub fn apply(&self, action: Interact, time: Timestamp) -> Option<InteractionResult> {
+	pub fn invalid_odds(&self) -> Option<f32> {
+		self.level_odds.iter()
+			.chain(self.extra_items.iter().map(|(_, odds)| odds))
+			.copied()
+			.find(|odds| !(0.0..=1.0).contains(odds))
+	}
+	
+	/// The random seed should differ for every place and time
+	pub fn apply(&self, action: Interact, rind: u32) -> Option<InteractionResult> {
 		if self.action_type == action.typ && action.level >= self.min_level {
 			let relative_level = (action.level - self.min_level) as usize;
 			let odds = if relative_level < self.level_odds.len() {
@@ -105,15 +166,22 @@ impl Interactable {
 			} else {
 				1.0
 			};
+			// extra items only come along when the interaction succeeds
+			let success = odds >= random::random_float(rind ^ 84217);
 			Some(InteractionResult {
 				remains: self.remains,
 				items: [
 					action.received(),
-					if odds >= random::random_float(time.random_seed() ^ 84217) {
+					if success {
 						self.items.clone()
 					} else {
 						Vec::new()
-					}
+					},
+					self.extra_items.iter()
+						.enumerate()
+						.filter(|(i, (_, odds))| success && *odds >= random::random_float(rind ^ (27631 + *i as u32)))
+						.map(|(_, (item, _))| *item)
+						.collect()
 				].concat(),
 				..Default::default()
 			})
@@ -128,9 +196,12 @@ impl Interactable {
 pub struct InteractionResult {
 	pub remains: Option<Structure>,
 	pub remains_ground: Option<Ground>,
+	pub fertility: Option<u8>,
 	pub items: Vec<Item>,
 	pub cost: HashMap<Item, usize>,
 	pub message: Option<(SoundType, String)>,
 	pub claim: bool,
 	pub build: bool,
+	pub recipe: Option<RecipeId>,
+	pub wear: bool,
 }
@@ -25,6 +25,11 @@ pub trait BaseMap {
 	}
 	
 	fn player_spawn(&self) -> Pos;
+
+	/// The seed for things like the weather that aren't part of the map itself
+	fn seed(&self) -> u32 {
+		0
+	}
 }
 
 
@@ -0,0 +1,386 @@
+
+use enum_dispatch::enum_dispatch;
+use crate::{
+	pos::{Pos, Direction, Area},
+	controls::Plan,
+	creatures::CreatureId,
+	creaturemap::{CreatureMap, CreatureTile},
+	creature::Faction,
+	timestamp::{Timestamp, Duration, DayPhase},
+	pathfinding,
+	random,
+};
+
+/// Everything a creature knows when deciding what to do next
+pub struct Context<'a> {
+	pub me: CreatureTile,
+	pub home: Pos,
+	pub target: Option<CreatureId>,
+	/// The player that tamed this creature
+	pub owner: Option<CreatureId>,
+	/// The claim of the owner, where tame creatures are kept when left alone
+	pub pen: Option<Area>,
+	pub aggro_distance: i32,
+	pub give_up_distance: i32,
+	pub creature_map: &'a CreatureMap,
+	pub walkable: &'a dyn Fn(Pos) -> bool,
+	/// Whether a position is on ground where no fighting is allowed
+	pub safe: &'a dyn Fn(Pos) -> bool,
+	pub time: Timestamp,
+	pub day_length: Duration,
+	pub rind: u32,
+}
+
+impl Context<'_> {
+	fn can_walk(&self, direction: Direction) -> bool {
+		(self.walkable)(self.me.pos + direction)
+	}
+
+	fn step_towards(&self, goal: Pos) -> Option<Direction> {
+		pathfinding::first_step(self.me.pos, goal, pathfinding::NPC_BUDGET, |p| p == goal || (self.walkable)(p))
+	}
+
+	/// Whether a step would take a penned creature out of its pen
+	fn leaves_pen(&self, direction: Direction) -> bool {
+		self.pen.is_some_and(|pen| pen.contains(self.me.pos) && !pen.contains(self.me.pos + direction))
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+	Act(Plan),
+	/// Do nothing this time
+	Wait,
+	/// Leave the decision to the next behaviour
+	Pass,
+}
+
+#[enum_dispatch]
+pub trait Behaviour {
+	fn decide(&self, context: &mut Context) -> Decision;
+}
+
+#[enum_dispatch(Behaviour)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mind {
+	Follow,
+	Hunt,
+	Flee,
+	Herd,
+	Rest,
+	Wander,
+}
+
+/// Ask each behaviour in turn until one of them makes a decision
+pub fn plan(behaviours: &[Mind], context: &mut Context) -> Option<Plan> {
+	for behaviour in behaviours {
+		match behaviour.decide(context) {
+			Decision::Act(plan) => return Some(plan),
+			Decision::Wait => return None,
+			Decision::Pass => {}
+		}
+	}
+	None
+}
+
+
+/// Walk after the owner while they are in sight.
+/// Wherever the owner leaves the creature becomes its new home,
+/// unless the owner has a claim: then the creature goes back to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Follow {
+	pub distance: i32,
+	pub sight: i32,
+}
+
+impl Behaviour for Follow {
+	fn decide(&self, context: &mut Context) -> Decision {
+		let pos = context.me.pos;
+		let Some(owner) = context.owner.and_then(|id| context.creature_map.get_creature(&id)) else {
+			return Decision::Pass;
+		};
+		if pos.distance_to(owner.pos) > self.sight {
+			if let Some(pen) = context.pen.filter(|pen| !pen.contains(context.home)) {
+				context.home = pen.center();
+			}
+			return Decision::Pass;
+		}
+		context.home = pos;
+		if pos.distance_to(owner.pos) <= self.distance {
+			return Decision::Pass;
+		}
+		context.step_towards(owner.pos).map_or(Decision::Wait, |direction| Decision::Act(Plan::Move(direction)))
+	}
+}
+
+/// Chase and attack enemies that come close
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hunt;
+
+impl Behaviour for Hunt {
+	fn decide(&self, context: &mut Context) -> Decision {
+		let pos = context.me.pos;
+		if let Some(target_id) = context.target {
+			let in_reach = context.creature_map.get_creature(&target_id)
+				.is_some_and(|target| pos.distance_to(target.pos) <= context.give_up_distance && !(context.safe)(target.pos));
+			if !in_reach {
+				context.target = None;
+			}
+		}
+		if context.target.is_none() {
+			context.target = context.creature_map.nearby(pos, context.aggro_distance)
+				.filter(|other| context.me.faction.is_enemy(other.faction) && !(context.safe)(other.pos))
+				.min_by_key(|other| pos.distance_to(other.pos))
+				.map(|other| other.id);
+		}
+		let Some(target) = context.target.and_then(|id| context.creature_map.get_creature(&id)) else {
+			return Decision::Pass;
+		};
+		if pos.distance_to(target.pos) <= 1 {
+			return Decision::Act(Plan::Fight(pos.directions_to(target.pos).first().cloned()));
+		}
+		if let Some(direction) = context.step_towards(target.pos) {
+			return Decision::Act(Plan::Move(direction));
+		}
+		let mut directions: Vec<Direction> = pos.directions_to(target.pos)
+			.into_iter()
+			.filter(|d| context.can_walk(*d))
+			.collect();
+		if directions.is_empty() {
+			directions = Direction::DIRECTIONS
+				.into_iter()
+				.filter(|d| context.can_walk(*d))
+				.collect();
+			if directions.is_empty() {
+				return Decision::Wait;
+			}
+		}
+		Decision::Act(Plan::Move(*random::pick(random::randomize_u32(context.rind + 386), &directions)))
+	}
+}
+
+/// Run away from creatures of a faction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flee {
+	pub from: Faction,
+	pub distance: i32,
+}
+
+impl Behaviour for Flee {
+	fn decide(&self, context: &mut Context) -> Decision {
+		let pos = context.me.pos;
+		let Some(threat) = context.creature_map.nearby(pos, self.distance)
+				.filter(|other| other.faction == self.from && other.id != context.me.id)
+				.min_by_key(|other| pos.distance_to(other.pos)) else {
+			return Decision::Pass;
+		};
+		let away = Direction::DIRECTIONS
+			.into_iter()
+			.filter(|d| context.can_walk(*d))
+			.max_by_key(|d| (pos + *d).distance_to(threat.pos));
+		match away {
+			Some(direction) if (pos + direction).distance_to(threat.pos) > pos.distance_to(threat.pos) =>
+				Decision::Act(Plan::Move(direction)),
+			_ => Decision::Wait
+		}
+	}
+}
+
+/// Stay close to other creatures of the same kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Herd {
+	pub distance: i32,
+	pub sight: i32,
+}
+
+impl Behaviour for Herd {
+	fn decide(&self, context: &mut Context) -> Decision {
+		let pos = context.me.pos;
+		let nearest = context.creature_map.nearby(pos, self.sight)
+			.filter(|other| other.kind == context.me.kind && other.id != context.me.id)
+			.min_by_key(|other| pos.distance_to(other.pos));
+		match nearest {
+			Some(other) if pos.distance_to(other.pos) > self.distance && random::percentage(context.rind + 219, 20) =>
+				context.step_towards(other.pos).map_or(Decision::Pass, |direction| Decision::Act(Plan::Move(direction))),
+			_ => Decision::Pass
+		}
+	}
+}
+
+/// Go home and stay there during part of the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rest {
+	pub during: DayPhase,
+}
+
+impl Behaviour for Rest {
+	fn decide(&self, context: &mut Context) -> Decision {
+		if context.time.day_phase(context.day_length) != self.during {
+			return Decision::Pass;
+		}
+		if context.me.pos != context.home && random::percentage(context.rind + 677, 20) {
+			if let Some(direction) = context.step_towards(context.home) {
+				return Decision::Act(Plan::Move(direction));
+			}
+		}
+		Decision::Wait
+	}
+}
+
+/// Walk around randomly, sometimes returning home
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wander;
+
+impl Behaviour for Wander {
+	fn decide(&self, context: &mut Context) -> Decision {
+		if !random::percentage(context.rind + 543, 10) {
+			return Decision::Wait;
+		}
+		let home_step = if context.me.pos != context.home && random::percentage(context.rind + 471, 10) {
+				context.step_towards(context.home)
+			} else {
+				None
+			};
+		let direction = home_step.unwrap_or_else(|| *random::pick(random::randomize_u32(context.rind + 385), &Direction::DIRECTIONS));
+		if context.leaves_pen(direction) {
+			return Decision::Wait;
+		}
+		Decision::Act(Plan::Move(direction))
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::cell::RefCell;
+	use crate::{
+		creature::{Creature, CreatureType},
+		creatures::SpawnId,
+		timestamp::DEFAULT_DAY_LENGTH,
+	};
+
+	fn creature(typ: CreatureType, pos: Pos) -> RefCell<Creature> {
+		let id = match typ {
+			CreatureType::Player => CreatureId::Player(crate::player::PlayerId::create("tester").unwrap()),
+			_ => CreatureId::Spawned(SpawnId(pos)),
+		};
+		RefCell::new(Creature::spawn_npc(id, pos, typ))
+	}
+
+	fn decide(behaviour: Mind, me: &RefCell<Creature>, others: &[RefCell<Creature>], walkable: &dyn Fn(Pos) -> bool, time: Timestamp) -> (Decision, Option<CreatureId>) {
+		decide_with_safe(behaviour, me, others, walkable, &|_| false, time)
+	}
+
+	fn decide_with_safe(behaviour: Mind, me: &RefCell<Creature>, others: &[RefCell<Creature>], walkable: &dyn Fn(Pos) -> bool, safe: &dyn Fn(Pos) -> bool, time: Timestamp) -> (Decision, Option<CreatureId>) {
+		let creature_map = CreatureMap::new(others.iter().chain([me]).map(RefCell::borrow));
+		let me = me.borrow();
+		let mut context = Context {
+			me: CreatureTile::new(&me),
+			home: me.pos,
+			target: None,
+			aggro_distance: 4,
+			give_up_distance: 10,
+			owner: me.owner().map(CreatureId::Player),
+			pen: None,
+			creature_map: &creature_map,
+			walkable,
+			safe,
+			time,
+			day_length: DEFAULT_DAY_LENGTH,
+			rind: time.random_seed(),
+		};
+		(behaviour.decide(&mut context), context.target)
+	}
+
+	#[test]
+	fn hunter_walks_around_wall() {
+		let worm = creature(CreatureType::Worm, Pos::new(0, 0));
+		let player = creature(CreatureType::Player, Pos::new(3, 0));
+		let wall = |p: Pos| !(p.x == 1 && p.y >= -1 && p.y <= 3);
+		let (decision, target) = decide(Mind::Hunt(Hunt), &worm, &[player], &wall, Timestamp(0));
+		assert_eq!(decision, Decision::Act(Plan::Move(Direction::North)));
+		assert!(target.is_some());
+	}
+
+	#[test]
+	fn hunter_leaves_sanctuary_alone() {
+		let worm = creature(CreatureType::Worm, Pos::new(0, 0));
+		let player = creature(CreatureType::Player, Pos::new(2, 0));
+		let sanctuary = |p: Pos| p.x >= 2;
+		let (decision, target) = decide_with_safe(Mind::Hunt(Hunt), &worm, &[player], &|_| true, &sanctuary, Timestamp(0));
+		assert_eq!(decision, Decision::Pass);
+		assert_eq!(target, None);
+	}
+
+	#[test]
+	fn tame_creatures_follow_their_owner() {
+		let follow = Mind::Follow(Follow { distance: 2, sight: 8 });
+		let sheep = creature(CreatureType::Sheep, Pos::new(0, 0));
+		let player = creature(CreatureType::Player, Pos::new(5, 0));
+		assert_eq!(decide(follow, &sheep, std::slice::from_ref(&player), &|_| true, Timestamp(0)).0, Decision::Pass);
+		let owner = player.borrow().id;
+		sheep.borrow_mut().tame(*owner.player().unwrap(), Timestamp(0));
+		assert_eq!(decide(follow, &sheep, &[player], &|_| true, Timestamp(0)).0, Decision::Act(Plan::Move(Direction::East)));
+		let far_player = creature(CreatureType::Player, Pos::new(20, 0));
+		assert_eq!(decide(follow, &sheep, &[far_player], &|_| true, Timestamp(0)).0, Decision::Pass);
+	}
+
+	#[test]
+	fn tame_creatures_go_back_to_the_pen() {
+		let sheep = creature(CreatureType::Sheep, Pos::new(0, 0));
+		sheep.borrow_mut().tame(crate::player::PlayerId::create("tester").unwrap(), Timestamp(0));
+		let player = creature(CreatureType::Player, Pos::new(20, 0));
+		let creature_map = CreatureMap::new([player.borrow(), sheep.borrow()].into_iter());
+		let pen = Area::centered(Pos::new(40, 0), Pos::new(9, 9));
+		let mut context = Context {
+			me: CreatureTile::new(&sheep.borrow()),
+			home: Pos::new(0, 0),
+			target: None,
+			owner: sheep.borrow().owner().map(CreatureId::Player),
+			pen: Some(pen),
+			aggro_distance: 0,
+			give_up_distance: 0,
+			creature_map: &creature_map,
+			walkable: &|_| true,
+			safe: &|_| false,
+			time: Timestamp(0),
+			day_length: DEFAULT_DAY_LENGTH,
+			rind: 0,
+		};
+		assert_eq!(Follow { distance: 2, sight: 8 }.decide(&mut context), Decision::Pass);
+		assert_eq!(context.home, Pos::new(40, 0));
+		context.me.pos = Pos::new(44, 0);
+		assert!(context.leaves_pen(Direction::East));
+		assert!(!context.leaves_pen(Direction::West));
+	}
+
+	#[test]
+	fn prey_flees_from_players() {
+		let frog = creature(CreatureType::Frog, Pos::new(0, 0));
+		let player = creature(CreatureType::Player, Pos::new(2, 0));
+		let flee = Mind::Flee(Flee { from: Faction::Player, distance: 3 });
+		assert_eq!(decide(flee, &frog, &[player], &|_| true, Timestamp(0)).0, Decision::Act(Plan::Move(Direction::West)));
+		let cornered = |p: Pos| p.x > -1 && p.y == 0;
+		let player = creature(CreatureType::Player, Pos::new(2, 0));
+		assert_eq!(decide(flee, &frog, &[player], &cornered, Timestamp(0)).0, Decision::Wait);
+	}
+
+	#[test]
+	fn herd_ignores_other_kinds() {
+		let herd = Mind::Herd(Herd { distance: 2, sight: 20 });
+		let frog = creature(CreatureType::Frog, Pos::new(0, 0));
+		let worm = creature(CreatureType::Worm, Pos::new(8, 0));
+		assert_eq!(decide(herd, &frog, &[worm], &|_| true, Timestamp(0)).0, Decision::Pass);
+		let other_frog = [creature(CreatureType::Frog, Pos::new(8, 0))];
+		assert!((0..50).any(|t| decide(herd, &frog, &other_frog, &|_| true, Timestamp(t)).0 == Decision::Act(Plan::Move(Direction::East))));
+	}
+
+	#[test]
+	fn rests_only_in_its_phase() {
+		let rest = Mind::Rest(Rest { during: DayPhase::Night });
+		let worm = creature(CreatureType::Worm, Pos::new(0, 0));
+		assert_eq!(decide(rest, &worm, &[], &|_| true, Timestamp(0)).0, Decision::Pass);
+		assert_eq!(decide(rest, &worm, &[], &|_| true, Timestamp(DEFAULT_DAY_LENGTH.0 * 3 / 4)).0, Decision::Wait);
+	}
+}
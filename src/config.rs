@@ -5,6 +5,8 @@ use std::path::{PathBuf};
 
 use crate::{
 	server::Address,
+	creature::PvpPolicy,
+	timestamp::MIN_DAY_LENGTH,
 };
 
 #[derive(Debug, Parser)]
@@ -68,6 +70,19 @@ pub struct WorldConfig {
 	#[arg(long)]
 	pub tls_cert: Option<PathBuf>,
 
+	/// A JSON content pack that overrides built-in definitions and adds recipes.
+	/// It can't add new items, structures, crops or creatures
+	#[arg(long)]
+	pub content: Option<PathBuf>,
+
+	/// Whether players can fight each other. Overrides the policy stored in the world
+	#[arg(long, value_enum)]
+	pub pvp: Option<PvpPolicy>,
+
+	/// The number of steps in a full day and night. Overrides the length stored in the world
+	#[arg(long, value_parser = clap::value_parser!(i64).range(MIN_DAY_LENGTH.0..))]
+	pub day_length: Option<i64>,
+
 	/// The time (in milliseconds) between two steps
 	#[arg(long, default_value_t=100)]
 	pub step_duration: u64,
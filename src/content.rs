@@ -0,0 +1,294 @@
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::fs;
+use std::fmt;
+use std::io::ErrorKind;
+use std::sync::OnceLock;
+use serde::Deserialize;
+use crate::{
+	item::{Item, Weapon},
+	action::{Action, CraftType, Interactable},
+	tile::Structure,
+	crop::CropType,
+	creature::{CreatureType, Loot},
+	equipment::Slot,
+	timestamp::{Duration, Season},
+	errors::AnyError,
+};
+
+static CONTENT: OnceLock<ContentPack> = OnceLock::new();
+
+#[derive(Debug)]
+pub enum ContentError {
+	MissingResource(AnyError),
+	InvalidResource(AnyError),
+	InvalidEntry{entry: String, reason: String},
+	AlreadyInstalled,
+}
+
+impl fmt::Display for ContentError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::MissingResource(err) => write!(f, "missing content pack: {}", err),
+			Self::InvalidResource(err) => write!(f, "invalid content pack: {}", err),
+			Self::InvalidEntry{entry, reason} => write!(f, "invalid entry {}: {}", entry, reason),
+			Self::AlreadyInstalled => write!(f, "a content pack is already installed"),
+		}
+	}
+}
+
+macro_rules! invalid {
+	($entry:expr, $($reason:tt)*) => {
+		Err(ContentError::InvalidEntry{entry: $entry, reason: format!($($reason)*)})
+	}
+}
+
+/// Overrides for the built-in content.
+/// Entries are keyed by existing items, structures, crops and creatures,
+/// so a pack can only change what is already in the game: new kinds still need code.
+/// The only thing a pack can add is recipes between existing items.
+/// Everything that is not mentioned in the pack keeps its built-in definition.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContentPack {
+	pub items: HashMap<Item, ItemDef>,
+	pub recipes: Vec<RecipeDef>,
+	pub structures: HashMap<Structure, StructureDef>,
+	pub crops: HashMap<CropType, CropDef>,
+	pub creatures: HashMap<CreatureType, CreatureDef>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ItemDef {
+	pub name: Option<String>,
+	pub description: Option<String>,
+	pub actions: Option<Vec<Action>>,
+	pub durability: Option<u32>,
+	pub stack_size: Option<usize>,
+	pub weapon: Option<Weapon>,
+	pub armour: Option<i32>,
+	pub slot: Option<Slot>,
+	pub capacity: Option<usize>,
+	pub food: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeDef {
+	pub station: CraftType,
+	pub ingredient: Item,
+	pub product: Item,
+	#[serde(default)]
+	pub cost: HashMap<Item, usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StructureDef {
+	pub description: Option<String>,
+	pub interactions: Option<Vec<Interactable>>,
+	pub grow: Option<(i64, Structure, Option<Structure>)>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CropDef {
+	pub description: Option<String>,
+	pub next: Option<(i64, CropType)>,
+	pub grow: Option<(i64, Structure)>,
+	pub fertilized_grow: Option<CropType>,
+	pub seasons: Option<Vec<Season>>,
+	pub regrow: Option<CropType>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CreatureDef {
+	pub name: Option<String>,
+	pub health: Option<i32>,
+	pub attack: Option<i32>,
+	pub aggro_distance: Option<i32>,
+	pub give_up_distance: Option<i32>,
+	pub walk_cooldown: Option<Duration>,
+	pub attack_cooldown: Option<Duration>,
+	pub loot: Option<Vec<Loot>>,
+}
+
+impl ContentPack {
+
+	pub fn load(path: &Path) -> Result<Self, ContentError> {
+		let text = fs::read_to_string(path).map_err(|err| {
+			if err.kind() == ErrorKind::NotFound {
+				ContentError::MissingResource(Box::new(err))
+			} else {
+				ContentError::InvalidResource(Box::new(err))
+			}
+		})?;
+		let pack: Self = serde_json::from_str(&text).map_err(|err| ContentError::InvalidResource(Box::new(err)))?;
+		pack.validate()?;
+		Ok(pack)
+	}
+
+	pub fn validate(&self) -> Result<(), ContentError> {
+		for (item, def) in self.items.iter() {
+			if def.durability == Some(0) {
+				return invalid!(format!("items.{:?}.durability", item), "durability must be positive");
+			}
+			if def.stack_size == Some(0) {
+				return invalid!(format!("items.{:?}.stack_size", item), "stack size must be positive");
+			}
+			if let Some(weapon) = def.weapon {
+				if weapon.reach < 1 || weapon.cooldown.0 <= 0 || weapon.damage < 0 {
+					return invalid!(format!("items.{:?}.weapon", item), "reach and cooldown must be positive and damage can't be negative");
+				}
+			}
+			if def.armour.is_some_and(|armour| armour < 0) {
+				return invalid!(format!("items.{:?}.armour", item), "armour can't be negative");
+			}
+			if def.food.is_some_and(|food| food < 0) {
+				return invalid!(format!("items.{:?}.food", item), "food can't be negative");
+			}
+			for (i, action) in def.actions.iter().flatten().enumerate() {
+				validate_action(format!("items.{:?}.actions[{}]", item, i), action)?;
+			}
+		}
+		for (i, recipe) in self.recipes.iter().enumerate() {
+			let entry = format!("recipes[{}]", i);
+			if recipe.ingredient == Item::Nothing {
+				return invalid!(entry, "a recipe needs an ingredient");
+			}
+			validate_action(entry, &recipe.action())?;
+		}
+		for (structure, def) in self.structures.iter() {
+			if let Some((steps, _, _)) = def.grow {
+				if steps <= 0 {
+					return invalid!(format!("structures.{:?}.grow", structure), "growing must take a positive number of steps, not {}", steps);
+				}
+			}
+			for (i, interactable) in def.interactions.iter().flatten().enumerate() {
+				if let Some(odds) = interactable.invalid_odds() {
+					return invalid!(format!("structures.{:?}.interactions[{}]", structure, i), "level odds must be between 0 and 1, not {}", odds);
+				}
+			}
+		}
+		for (crop, def) in self.crops.iter() {
+			for steps in [def.next.map(|n| n.0), def.grow.map(|g| g.0)].into_iter().flatten() {
+				if steps <= 0 {
+					return invalid!(format!("crops.{:?}", crop), "growing must take a positive number of steps, not {}", steps);
+				}
+			}
+		}
+		for (creature, def) in self.creatures.iter() {
+			if def.health.is_some_and(|health| health <= 0) {
+				return invalid!(format!("creatures.{:?}.health", creature), "health must be positive");
+			}
+			for cooldown in [def.walk_cooldown, def.attack_cooldown].into_iter().flatten() {
+				if cooldown.0 <= 0 {
+					return invalid!(format!("creatures.{:?}", creature), "cooldowns must be positive, not {}", cooldown.0);
+				}
+			}
+			for (i, loot) in def.loot.iter().flatten().enumerate() {
+				if !(0.0..=1.0).contains(&loot.odds) {
+					return invalid!(format!("creatures.{:?}.loot[{}]", creature, i), "odds must be between 0 and 1, not {}", loot.odds);
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl RecipeDef {
+	pub fn action(&self) -> Action {
+		Action::Craft(self.station, self.product, self.cost.clone())
+	}
+}
+
+fn validate_action(entry: String, action: &Action) -> Result<(), ContentError> {
+	match action {
+		Action::Craft(_, product, cost) => {
+			if *product == Item::Nothing {
+				return invalid!(entry, "crafting must produce an item");
+			}
+			validate_cost(entry, cost)
+		}
+		Action::Build(structure, cost) => {
+			if structure.is_open() {
+				return invalid!(entry, "building must create a structure");
+			}
+			validate_cost(entry, cost)
+		}
+		_ => Ok(())
+	}
+}
+
+fn validate_cost(entry: String, cost: &HashMap<Item, usize>) -> Result<(), ContentError> {
+	if let Some((item, _)) = cost.iter().find(|(item, n)| **n == 0 || **item == Item::Nothing) {
+		return invalid!(entry, "invalid cost for item {:?}", item);
+	}
+	Ok(())
+}
+
+/// Make the content pack available to the rest of the game.
+/// This can only be done once, before the world starts.
+pub fn install(pack: ContentPack) -> Result<(), ContentError> {
+	CONTENT.set(pack).map_err(|_| ContentError::AlreadyInstalled)
+}
+
+fn get() -> &'static ContentPack {
+	CONTENT.get_or_init(ContentPack::default)
+}
+
+pub fn item(item: Item) -> Option<&'static ItemDef> {
+	get().items.get(&item)
+}
+
+pub fn recipes(ingredient: Item) -> impl Iterator<Item=Action> {
+	get().recipes.iter()
+		.filter(move |recipe| recipe.ingredient == ingredient)
+		.map(RecipeDef::action)
+}
+
+pub fn structure(structure: Structure) -> Option<&'static StructureDef> {
+	get().structures.get(&structure)
+}
+
+pub fn crop(crop: CropType) -> Option<&'static CropDef> {
+	get().crops.get(&crop)
+}
+
+pub fn creature(creature: CreatureType) -> Option<&'static CreatureDef> {
+	get().creatures.get(&creature)
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::hashmap;
+
+	#[test]
+	fn parses_pack() {
+		let pack: ContentPack = serde_json::from_str(r#"{
+			"items": {"flower": {"description": "A very pretty flower"}},
+			"recipes": [{"station": "marker", "ingredient": "stone", "product": "marker_stone", "cost": {"pebble": 3}}],
+			"structures": {"Reed": {"interactions": [{"action_type": "take", "items": ["reed"]}]}},
+			"crops": {"GreenSeed": {"next": [3, "GreenSeedling"]}},
+			"creatures": {"Worm": {"health": 20}}
+		}"#).unwrap();
+		assert!(pack.validate().is_ok());
+		assert_eq!(pack.recipes[0].action(), Action::Craft(CraftType::Marker, Item::MarkerStone, hashmap![Item::Pebble => 3]));
+	}
+
+	#[test]
+	fn invalid_entry_is_named() {
+		let pack: ContentPack = serde_json::from_str(r#"{
+			"creatures": {"Frog": {"walk_cooldown": 0}}
+		}"#).unwrap();
+		let err = pack.validate().unwrap_err();
+		let ContentError::InvalidEntry{entry, ..} = &err else { panic!("zero cooldown accepted") };
+		assert_eq!(entry, "creatures.Frog");
+		assert_eq!(err.to_string(), "invalid entry creatures.Frog: cooldowns must be positive, not 0");
+	}
+}
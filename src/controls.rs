@@ -4,10 +4,13 @@ use serde::{Serialize, Deserialize};
 use crate::{
 	player::{PlayerId, PlayerConfigMsg},
 	pos::{Direction, Pos},
+	action::CraftType,
+	item::Item,
+	equipment::Slot,
 };
 
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all="lowercase")]
 pub enum Plan {
 	Move(Direction),
@@ -18,6 +21,18 @@ pub enum Plan {
 	InspectItem(usize),
 	Fight(Option<Direction>),
 	Interact(Option<usize>, Option<Direction>),
+	Recipes(RecipeQuery),
+	Craft(Option<Direction>, Item, usize),
+	GoTo(Pos),
+	Equip(usize),
+	Unequip(Slot),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum RecipeQuery {
+	Station(CraftType),
+	Item(usize),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,14 +40,24 @@ pub enum Plan {
 pub enum DirectChange {
 	MoveItem(usize, usize),
 	Movement(Option<Direction>),
-	Path(Vec<Pos>),
+	NameItem(usize, Option<String>),
+	Select(Option<usize>),
+	Pvp(bool),
+}
+
+/// Settings for the land a player has claimed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum ClaimChange {
+	AllowFire(bool),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Control {
 	Plan(Plan),
-	Direct(DirectChange)
+	Direct(DirectChange),
+	Claim(ClaimChange),
 }
 
 #[derive(Debug, Clone)]
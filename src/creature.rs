@@ -1,40 +1,61 @@
 
 use std::cell::RefMut;
+use std::collections::HashSet;
 use core::ops::Not;
 use serde::{Serialize, Deserialize};
 use enum_assoc::Assoc;
+use clap::ValueEnum;
 use crate::{
 	sprite::Sprite,
-	pos::{Pos, Direction},
+	pos::{Pos, Direction, Area},
 	timestamp::Duration,
 	inventory::{Inventory, InventorySave},
 	worldmessages::SoundType,
-	timestamp::Timestamp,
-	controls::{Control, Plan, DirectChange},
-	creatures::CreatureId,
+	timestamp::{Timestamp, DayPhase},
+	controls::{Plan, DirectChange},
+	creatures::{CreatureId, SpawnId},
 	creaturemap::{CreatureMap, CreatureTile},
 	map::Map,
+	recipe::{Recipe, RecipeId},
+	worldmessages::RecipeBookMessage,
 	random,
+	content,
+	item::Item,
+	equipment::{Equipment, EquipmentSave, Slot},
+	behaviour::{self, Mind, Follow, Hunt, Flee, Herd, Rest, Wander},
+	player::PlayerId,
 };
 
+pub const MAX_ENERGY: i32 = 100;
+/// How much slower starving creatures walk
+const STARVING_SLOWDOWN: i64 = 2;
+
 #[derive(Debug, Clone)]
 pub struct Creature {
 	typ: CreatureType,
 	pub id: CreatureId,
 	pub pos: Pos,
 	pub inventory: Inventory,
+	pub equipment: Equipment,
 	pub heard_sounds: Vec<(SoundType, String)>,
 	activity: Option<Activity>,
 	pub plan: Option<Plan>,
 	pub name: String,
 	health: i32,
 	last_autoheal: Timestamp,
+	energy: i32,
 	wounds: Vec<Wound>,
 	target: Option<CreatureId>,
 	home: Pos,
 	is_dead: bool,
 	movement: Option<Direction>,
 	pub path: Vec<Pos>,
+	known_recipes: HashSet<RecipeId>,
+	pvp: bool,
+	pub recipe_book: Option<RecipeBookMessage>,
+	owner: Option<PlayerId>,
+	fed: bool,
+	last_produce: Timestamp,
 }
 
 impl Creature {
@@ -45,11 +66,13 @@ impl Creature {
 			id,
 			pos,
 			inventory: Inventory::empty(),
+			equipment: Equipment::default(),
 			heard_sounds: Vec::new(),
 			activity: None,
 			plan: None,
 			name: typ.name().to_string(),
 			health: typ.health(),
+			energy: MAX_ENERGY,
 			wounds: Vec::new(),
 			target: None,
 			home: pos,
@@ -57,30 +80,109 @@ impl Creature {
 			movement: None,
 			path: Vec::new(),
 			last_autoheal: Timestamp::zero(),
+			known_recipes: HashSet::new(),
+			pvp: false,
+			recipe_book: None,
+			owner: None,
+			fed: false,
+			last_produce: Timestamp::zero(),
 		}
 	}
 
 	pub fn load_player(id: CreatureId, saved: PlayerSave) -> Self {
+		let equipment = Equipment::load(saved.equipment);
 		Self {
 			name: saved.name,
-			inventory: Inventory::load(saved.inventory),
+			inventory: {
+				let mut inventory = Inventory::load(saved.inventory);
+				inventory.select(saved.selected);
+				inventory.set_capacity_bonus(equipment.capacity_bonus());
+				inventory
+			},
+			equipment,
 			health: saved.health,
+			energy: saved.energy,
+			known_recipes: saved.recipes,
+			pvp: saved.pvp,
 			..Self::spawn_npc(id, saved.pos, CreatureType::Player)
 		}
 	}
 
+	pub fn load_npc(saved: NpcSave) -> Self {
+		Self {
+			health: saved.health,
+			home: saved.home,
+			target: saved.target,
+			owner: saved.owner,
+			fed: saved.fed,
+			last_produce: saved.last_produce,
+			is_dead: saved.dead,
+			..Self::spawn_npc(saved.id(), saved.pos, saved.typ)
+		}
+	}
+
 	pub fn is_dead(&self) -> bool {
 		self.is_dead
 	}
 
+	pub fn is_wounded(&self) -> bool {
+		self.health < self.typ.health()
+	}
+
+	pub fn health(&self) -> i32 {
+		self.health
+	}
+
+	/// Give a creature back the wounds it had before it was unloaded
+	pub fn set_health(&mut self, health: i32) {
+		self.health = health.min(self.typ.health());
+	}
+
+	/// The item that is used when attacking or working without selecting an item
+	pub fn held_item(&self) -> Option<Item> {
+		self.equipment.get(Slot::Hand)
+	}
+
+	pub fn equip(&mut self, index: usize) {
+		if let Err(message) = self.equipment.equip(&mut self.inventory, index) {
+			self.hear(SoundType::BuildError, message);
+		}
+	}
+
+	pub fn unequip(&mut self, slot: Slot) {
+		if let Err(message) = self.equipment.unequip(&mut self.inventory, slot) {
+			self.hear(SoundType::BuildError, message);
+		}
+	}
+
+	pub fn combat_stats(&self) -> CombatStats {
+		let weapon = self.held_item().and_then(Item::weapon);
+		CombatStats {
+			damage: weapon.map_or_else(|| self.typ.attack(), |weapon| weapon.damage),
+			reach: weapon.map_or(1, |weapon| weapon.reach),
+			cooldown: weapon.map_or_else(|| self.typ.attack_cooldown(), |weapon| weapon.cooldown),
+			armour: self.equipment.armour(),
+		}
+	}
+
+	/// Whether this player has opted in to fighting other players
+	pub fn wants_pvp(&self) -> bool {
+		self.pvp
+	}
+
 	pub fn attack(&mut self, mut opponent: RefMut<Creature>, time: Timestamp) {
 		self.target = Some(opponent.id);
-		let damage = self.typ.attack();
+		let stats = self.combat_stats();
+		let damage = if stats.damage > 0 {
+				(stats.damage - opponent.equipment.armour()).max(1)
+			} else {
+				0
+			};
 		opponent.health -= damage;
 		self.activity = Some(Activity {
 			typ: ActivityType::Attack{ target: opponent.pos, damage },
 			start: time,
-			end: time + self.typ.attack_cooldown()
+			end: time + stats.cooldown
 		});
 		opponent.wounds.push(
 			Wound {
@@ -101,10 +203,62 @@ impl Creature {
 			name: self.name.clone(),
 			pos: self.pos,
 			inventory: self.inventory.save(),
+			selected: self.inventory.selected(),
+			equipment: self.equipment.save(),
+			pvp: self.pvp,
 			health: self.health.max(0),
+			energy: self.energy,
+			recipes: self.known_recipes.clone(),
+		}
+	}
+
+	pub fn save_npc(&self) -> NpcSave {
+		let origin = match self.id {
+			CreatureId::Spawned(spawn) => NpcOrigin::Spawn(spawn),
+			CreatureId::Bred(n) => NpcOrigin::Bred(n),
+			CreatureId::Player(_) => panic!("players can't be saved as npc: {:?}", self.id),
+		};
+		NpcSave {
+			origin,
+			typ: self.typ,
+			pos: self.pos,
+			health: self.health,
+			home: self.home,
+			target: self.target,
+			owner: self.owner,
+			fed: self.fed,
+			last_produce: self.last_produce,
+			dead: self.is_dead,
 		}
 	}
 
+	pub fn craft(&mut self, station: Pos, duration: Duration, time: Timestamp) {
+		self.activity = Some(Activity {
+			typ: ActivityType::Craft(station),
+			start: time,
+			end: time + duration
+		});
+	}
+
+	pub fn learn_recipe(&mut self, recipe: RecipeId) {
+		self.known_recipes.insert(recipe);
+	}
+
+	/// Show the known recipes among the given ones.
+	/// Recipes that the creature has all ingredients for are discovered automatically.
+	pub fn look_up_recipes(&mut self, recipes: impl Iterator<Item=Recipe>) {
+		let mut book = Vec::new();
+		for recipe in recipes {
+			if self.inventory.can_afford(&recipe.ingredients) {
+				self.known_recipes.insert(recipe.id);
+			}
+			if self.known_recipes.contains(&recipe.id) {
+				book.push(recipe.view(&self.inventory));
+			}
+		}
+		self.recipe_book = Some(book);
+	}
+
 	pub fn is_dying(&self, tick: Timestamp) -> bool {
 		self.activity.as_ref().is_some_and(|activity| matches!(activity.typ, ActivityType::Die(_)) && activity.is_active(tick))
 	}
@@ -113,7 +267,7 @@ impl Creature {
 		self.activity = Some(Activity {
 			typ: ActivityType::Walk(self.pos),
 			start: time,
-			end: time + Duration(self.typ.walk_cooldown().0 * self.pos.distance_to(newpos) as i64)
+			end: time + Duration(self.walk_cooldown().0 * self.pos.distance_to(newpos) as i64)
 		});
 		self.pos = newpos;
 	}
@@ -126,25 +280,23 @@ impl Creature {
 		}
 	}
 
-	pub fn control(&mut self, control: Control) {
-		match control {
-			Control::Plan(plan) => {
-				self.plan = Some(plan);
-				self.path = Vec::new();
-			}
-			Control::Direct(DirectChange::MoveItem(from, target)) => self.inventory.move_item(from, target),
-			Control::Direct(DirectChange::Movement(Some(direction))) => {
+	pub fn set_plan(&mut self, plan: Plan) {
+		self.plan = Some(plan);
+		self.path = Vec::new();
+	}
+
+	pub fn change(&mut self, change: DirectChange) {
+		match change {
+			DirectChange::MoveItem(from, target) => self.inventory.move_item(from, target),
+			DirectChange::NameItem(index, name) => self.inventory.name_item(index, name),
+			DirectChange::Select(index) => self.inventory.select(index),
+			DirectChange::Pvp(pvp) => self.pvp = pvp,
+			DirectChange::Movement(Some(direction)) => {
 				self.plan = Some(Plan::Move(direction));
 				self.movement = Some(direction);
 				self.path = Vec::new()
 			},
-			Control::Direct(DirectChange::Movement(None)) => {
-				self.movement = None;
-			}
-			Control::Direct(DirectChange::Path(mut path)) => {
-				path.truncate(32);
-				path.retain(|p| self.pos.distance_to(*p) <= 32);
-				self.path = path;
+			DirectChange::Movement(None) => {
 				self.movement = None;
 			}
 		}
@@ -156,13 +308,15 @@ impl Creature {
 
 	pub fn reset(&mut self, time: Timestamp) {
 		self.heard_sounds = Vec::new();
+		self.recipe_book = None;
 		if self.activity.as_ref().is_some_and(|activity| time > activity.end) {
 			self.activity = None;
 		}
 		self.wounds.retain(|wound| time - wound.time <= Duration(10));
 	}
 
-	pub fn update(&mut self, now: Timestamp) {
+	/// Returns whether the creature died
+	pub fn update(&mut self, now: Timestamp, sheltered: bool) -> bool {
 		if self.typ.mortal() && self.health <= 0 {
 			self.is_dead = true;
 			self.activity = Some(Activity {
@@ -170,13 +324,18 @@ impl Creature {
 				start: now,
 				end: now + Duration(10)
 			});
-			return;
+			return true;
+		}
+
+		if self.typ.hunger().is_some_and(|hunger| now.0 % hunger.0 == 0) {
+			self.energy = (self.energy - 1).max(0);
 		}
 
 		if self.health >= self.typ.health() {
 			self.last_autoheal = Timestamp::zero();
-		} else if let Some(autoheal) = self.typ.autoheal() {
-			let next_autoheal = self.last_autoheal + autoheal.cooldown;
+		} else if let Some(autoheal) = self.typ.autoheal().filter(|_| !self.is_starving()) {
+			let cooldown = if sheltered { autoheal.sheltered_cooldown } else { autoheal.cooldown };
+			let next_autoheal = self.last_autoheal + cooldown;
 			if now == next_autoheal {
 				self.health = (self.health + autoheal.amount).min(self.typ.health()).max(self.health).max(0);
 			}
@@ -184,114 +343,84 @@ impl Creature {
 				self.last_autoheal = now;
 			}
 		}
+		false
 	}
 
-	pub fn plan(&mut self, creature_map: &CreatureMap, map: &Map, time: Timestamp) {
+	/// Decide what to do next. Tame creatures are kept inside the pen when their owner has a claim
+	pub fn plan(&mut self, creature_map: &CreatureMap, map: &Map, time: Timestamp, pvp: PvpPolicy, pen: Option<Area>) {
+		if self.id.player().is_some() {
+			self.plan_player(creature_map, time, pvp);
+			return;
+		}
 		let ct = CreatureTile::new(self);
-		let can_walk = |d: &Direction| {
-			let p = self.pos + *d;
-			!creature_map.blocking(p, &ct) && !map.cell(p).blocking()
-		};
+		let hostile = self.faction().is_hostile();
+		let safe = |p: Pos| map.cell(p).ground.safe();
+		let walkable = |p: Pos| !(creature_map.blocking(p, &ct) || map.cell(p).blocking() || hostile && safe(p));
 		let rind = random::randomize_u32(
 			random::randomize_pos(self.home)
 				.wrapping_add(random::randomize_pos(self.pos))
 				.wrapping_add(time.0 as u32)
 		);
-		match self.typ.mind() {
-			Mind::Player => {
-				if self.plan.is_none() {
-					if let Some(direction) = self.movement {
-						self.plan = Some(Plan::Move(direction));
-						self.path = Vec::new();
-						return;
-					}
-					if let Some(idx) = self.path.iter().position(|p| *p == self.pos) {
-						self.path.drain(..(idx+1));
-					}
-					if let Some(path_next) = self.path.first() {
-						self.plan = Some(Plan::MoveD((*path_next - self.pos).normalize()));
-						return;
-					}
+		let mut context = behaviour::Context {
+			me: ct,
+			home: self.home,
+			target: self.target,
+			owner: self.owner.map(CreatureId::Player),
+			pen,
+			aggro_distance: self.typ.aggro_distance(),
+			give_up_distance: self.typ.give_up_distance(),
+			creature_map,
+			walkable: &walkable,
+			safe: &safe,
+			time,
+			day_length: map.day_length(),
+			rind,
+		};
+		if let Some(plan) = behaviour::plan(self.typ.mind(), &mut context) {
+			self.plan = Some(plan);
+		}
+		self.target = context.target;
+		self.home = context.home;
+	}
 
-					if self.target.is_none() {
-						for wound in self.wounds.iter().rev() {
-							let age = time - wound.time;
-							if age >= Duration(2) {
-								self.target = Some(wound.by);
-							}
-						}
-					}
-					if let Some(target_id) = self.target {
-						let Some(target) = creature_map.get_creature(&target_id) else {
-							self.target = None;
-							return;
-						};
-						if self.pos.distance_to(target.pos) > 1 {
-							self.target = None;
-							return;
-						}
-						self.plan = Some(Plan::Fight(self.pos.directions_to(target.pos).first().cloned()));
-					}
-				}
-			},
-			Mind::Idle => {
-				if random::percentage(rind + 543, 10) {
-					let directions = if self.pos != self.home && random::percentage(rind + 471, 10) {
-							self.pos.directions_to(self.home)
-						} else {
-							vec![Direction::North, Direction::South, Direction::East, Direction::West]
-						};
-					let direction = *random::pick(random::randomize_u32(rind + 385), &directions);
-					let control = Plan::Move(direction);
-					self.plan = Some(control);
-				}
+	fn plan_player(&mut self, creature_map: &CreatureMap, time: Timestamp, pvp: PvpPolicy) {
+		if self.plan.is_none() {
+			if let Some(direction) = self.movement {
+				self.plan = Some(Plan::Move(direction));
+				self.path = Vec::new();
+				return;
+			}
+			if let Some(idx) = self.path.iter().position(|p| *p == self.pos) {
+				self.path.drain(..(idx+1));
 			}
-			Mind::Aggressive => {
-				if let Some(target_id) = self.target {
-					if let Some(target) = creature_map.get_creature(&target_id) {
-						if self.pos.distance_to(target.pos) > self.typ.give_up_distance() {
-							self.target = None;
-						}
-					} else  {
-						self.target = None;
+			if let Some(path_next) = self.path.first() {
+				self.plan = Some(Plan::MoveD((*path_next - self.pos).normalize()));
+				return;
+			}
+
+			if self.target.is_none() {
+				for wound in self.wounds.iter().rev() {
+					let age = time - wound.time;
+					if age >= Duration(2) {
+						self.target = Some(wound.by);
 					}
 				}
-				if self.target.is_none() {
-					self.target = creature_map.nearby(self.pos, self.typ.aggro_distance())
-						.filter(|other| self.faction().is_enemy(other.faction))
-						.min_by_key(|other| self.pos.distance_to(other.pos))
-						.map(|other| other.id);
+			}
+			if let Some(target_id) = self.target {
+				let Some(target) = creature_map.get_creature(&target_id) else {
+					self.target = None;
+					return;
+				};
+				if self.pos.distance_to(target.pos) > 1 {
+					self.target = None;
+					return;
 				}
-				if let Some(target_id) = self.target {
-					let target = creature_map.get_creature(&target_id).unwrap();
-					if self.pos.distance_to(target.pos) <= 1 {
-						self.plan = Some(Plan::Fight(self.pos.directions_to(target.pos).first().cloned()));
-					} else {
-						let mut directions: Vec<Direction> = self.pos.directions_to(target.pos)
-							.into_iter()
-							.filter(can_walk)
-							.collect();
-						if directions.is_empty() {
-							directions = Direction::DIRECTIONS
-								.into_iter()
-								.filter(can_walk)
-								.collect();
-							if directions.is_empty() {
-								return;
-							}
-						}
-						let direction = *random::pick(random::randomize_u32(rind + 386), &directions);
-						self.plan = Some(Plan::Move(direction));
-					}
-				} else if random::percentage(rind + 543, 10) {
-					let directions = if self.pos != self.home && random::percentage(rind + 471, 10) {
-							self.pos.directions_to(self.home)
-						} else {
-							vec![Direction::North, Direction::South, Direction::East, Direction::West]
-						};
-					let direction = *random::pick(random::randomize_u32(rind + 385), &directions);
-					self.plan = Some(Plan::Move(direction));
+				let me = CreatureTile::new(self);
+				if !me.faction.can_attack(target.faction) && !pvp.allows(&me, target) {
+					self.target = None;
+					return;
 				}
+				self.plan = Some(Plan::Fight(self.pos.directions_to(target.pos).first().cloned()));
 			}
 		}
 	}
@@ -300,6 +429,113 @@ impl Creature {
 		self.typ.blocking()
 	}
 
+	/// Players don't die, they only run out of health
+	pub fn is_knocked_out(&self) -> bool {
+		!self.typ.mortal() && self.health <= 0
+	}
+
+	pub fn respawn(&mut self, pos: Pos) {
+		self.pos = pos;
+		self.health = self.typ.health();
+		self.plan = None;
+		self.path = Vec::new();
+		self.target = None;
+		self.activity = None;
+	}
+
+	/// The creature that dealt the most recent wound
+	pub fn killer(&self) -> Option<CreatureId> {
+		self.wounds.last().map(|wound| wound.by).filter(|by| *by != self.id)
+	}
+
+	/// Take damage that doesn't come from another creature
+	pub fn burn(&mut self, damage: i32, time: Timestamp) {
+		self.health -= damage;
+		self.wounds.push(
+			Wound {
+				damage,
+				time,
+				rind: random::randomize_u32(random::randomize_pos(self.pos) ^ time.random_seed()),
+				by: self.id
+			}
+		);
+	}
+
+	/// Decide which loot this creature leaves behind
+	pub fn roll_loot(&self, time: Timestamp) -> Vec<Loot> {
+		let seed = random::randomize_pos(self.pos) ^ time.random_seed();
+		self.typ.loot()
+			.into_iter()
+			.enumerate()
+			.filter(|(i, loot)| loot.odds >= random::random_float(seed.wrapping_add(*i as u32 * 7919)))
+			.map(|(_, loot)| loot)
+			.collect()
+	}
+
+	pub fn is_starving(&self) -> bool {
+		self.typ.hunger().is_some() && self.energy <= 0
+	}
+
+	fn walk_cooldown(&self) -> Duration {
+		let cooldown = self.typ.walk_cooldown();
+		if self.is_starving() {
+			Duration(cooldown.0 * STARVING_SLOWDOWN)
+		} else {
+			cooldown
+		}
+	}
+
+	/// Eat the item at the index. Returns false if it isn't food
+	pub fn eat(&mut self, index: usize) -> bool {
+		let Some(item) = self.inventory.get_item(index) else {
+			return false;
+		};
+		if item.food() <= 0 || self.typ.hunger().is_none() {
+			return false;
+		}
+		if self.energy >= MAX_ENERGY {
+			self.hear(SoundType::Explain, "You are not hungry".to_string());
+			return true;
+		}
+		self.inventory.take_one(index);
+		self.energy = (self.energy + item.food()).min(MAX_ENERGY);
+		true
+	}
+
+	/// The player that tamed this creature
+	pub fn owner(&self) -> Option<PlayerId> {
+		self.owner
+	}
+
+	pub fn tame(&mut self, owner: PlayerId, time: Timestamp) {
+		self.owner = Some(owner);
+		self.last_produce = time;
+	}
+
+	/// What a tame creature has made since it last produced something.
+	/// Every creature counts from when it was tamed so they don't all produce at once
+	pub fn produce(&mut self, time: Timestamp) -> Option<Item> {
+		let (item, interval) = self.typ.produce().filter(|_| self.owner.is_some())?;
+		if time < self.last_produce + interval {
+			return None;
+		}
+		self.last_produce = time;
+		Some(item)
+	}
+
+	pub fn is_fed(&self) -> bool {
+		self.fed
+	}
+
+	/// Fed tame creatures are ready to breed
+	pub fn set_fed(&mut self, fed: bool) {
+		self.fed = fed;
+	}
+
+	pub fn kind(&self) -> CreatureType {
+		self.typ
+	}
+
 	pub fn faction(&self) -> Faction {
 		self.typ.faction()
 	}
@@ -314,12 +550,18 @@ impl Creature {
 			health: (self.health.max(0), self.typ.health()),
 			wounds: self.wounds.iter().rev().cloned().collect(),
 			walk_speed: None,
+			combat: None,
+			energy: None,
+			pvp: None,
 		}
 	}
 
 	pub fn view_ext(&self, tick: Timestamp) -> CreatureView {
 		CreatureView {
-			walk_speed: Some((1, self.typ.walk_cooldown())),
+			walk_speed: Some((1, self.walk_cooldown())),
+			combat: Some(self.combat_stats()),
+			energy: self.typ.hunger().map(|_| (self.energy, MAX_ENERGY)),
+			pvp: Some(self.pvp),
 			..self.view(tick)
 		}
 	}
@@ -330,10 +572,21 @@ pub struct PlayerSave {
 	name: String,
 	pos: Pos,
 	inventory: InventorySave,
+	#[serde(default)]
+	selected: Option<usize>,
+	#[serde(default)]
+	equipment: EquipmentSave,
+	#[serde(default)]
+	pvp: bool,
 	#[serde(default="one")]
 	health: i32,
+	#[serde(default="full_energy")]
+	energy: i32,
+	#[serde(default)]
+	recipes: HashSet<RecipeId>,
 }
 fn one() -> i32 {1}
+fn full_energy() -> i32 {MAX_ENERGY}
 
 impl PlayerSave {
 	pub fn new(name: String, pos: Pos) -> Self {
@@ -341,7 +594,50 @@ impl PlayerSave {
 			name,
 			pos,
 			inventory: Vec::new(),
+			selected: None,
+			equipment: EquipmentSave::new(),
+			pvp: false,
 			health: CreatureType::Player.health(),
+			energy: MAX_ENERGY,
+			recipes: HashSet::new(),
+		}
+	}
+}
+
+/// Where a saved creature came from, which decides its id
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NpcOrigin {
+	Spawn(SpawnId),
+	Bred(u64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcSave {
+	#[serde(flatten)]
+	origin: NpcOrigin,
+	typ: CreatureType,
+	pos: Pos,
+	health: i32,
+	home: Pos,
+	#[serde(default)]
+	target: Option<CreatureId>,
+	#[serde(default)]
+	owner: Option<PlayerId>,
+	#[serde(default)]
+	fed: bool,
+	#[serde(default)]
+	last_produce: Timestamp,
+	/// Dead creatures are saved so their spawn point doesn't respawn them right away
+	#[serde(default)]
+	dead: bool,
+}
+
+impl NpcSave {
+	pub fn id(&self) -> CreatureId {
+		match self.origin {
+			NpcOrigin::Spawn(spawn) => CreatureId::Spawned(spawn),
+			NpcOrigin::Bred(n) => CreatureId::Bred(n),
 		}
 	}
 }
@@ -364,6 +660,20 @@ pub struct CreatureView {
 	blocking: bool,
 	#[serde(rename = "v", skip_serializing_if = "Option::is_none")]
 	walk_speed: Option<(i32, Duration)>,
+	#[serde(rename = "c", skip_serializing_if = "Option::is_none")]
+	combat: Option<CombatStats>,
+	#[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+	energy: Option<(i32, i32)>,
+	#[serde(rename = "f", skip_serializing_if = "Option::is_none")]
+	pvp: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CombatStats {
+	pub damage: i32,
+	pub reach: i32,
+	pub cooldown: Duration,
+	pub armour: i32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -373,6 +683,33 @@ pub enum Faction {
 	Evil
 }
 
+/// When players are allowed to fight each other
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all="kebab-case")]
+pub enum PvpPolicy {
+	#[default]
+	Off,
+	/// Everywhere except on sanctuary ground, which never allows combat
+	#[serde(alias = "outside-sanctuary")]
+	#[value(alias = "outside-sanctuary")]
+	On,
+	/// Only between players that have both opted in
+	OptIn,
+}
+
+impl PvpPolicy {
+	pub fn allows(self, attacker: &CreatureTile, defender: &CreatureTile) -> bool {
+		if attacker.faction != Faction::Player || defender.faction != Faction::Player || attacker.id == defender.id {
+			return false;
+		}
+		match self {
+			Self::Off => false,
+			Self::On => true,
+			Self::OptIn => attacker.pvp && defender.pvp,
+		}
+	}
+}
+
 impl Faction {
 	pub fn is_enemy(&self, other: Faction) -> bool {
 		matches!(
@@ -380,6 +717,16 @@ impl Faction {
 			(Faction::Player, Faction::Evil) | (Faction::Evil, Faction::Player)
 		)
 	}
+
+	/// Hostile creatures stay away from safe ground
+	pub fn is_hostile(&self) -> bool {
+		*self == Faction::Evil
+	}
+
+	/// Enemies can be attacked, and players can also hunt neutral creatures
+	pub fn can_attack(&self, other: Faction) -> bool {
+		self.is_enemy(other) || (*self == Faction::Player && other == Faction::Neutral)
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
@@ -395,6 +742,8 @@ enum ActivityType {
 	},
 	#[serde(rename = "D")]
 	Die(bool),
+	#[serde(rename = "C")]
+	Craft(Pos),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
@@ -427,60 +776,259 @@ struct Wound {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Assoc, Serialize, Deserialize)]
 #[func(fn sprite(self) -> Sprite)]
-#[func(fn name(&self) -> &str)]
-#[func(fn faction(self) -> Faction {Faction::Neutral})]
-#[func(fn health(self) -> i32 {1})]
-#[func(fn attack(self) -> i32 {0})]
-#[func(fn mind(self) -> Mind {Mind::Idle})]
-#[func(fn aggro_distance(self) -> i32 {-1})]
-#[func(fn give_up_distance(self) -> i32 {-1})]
-#[func(fn walk_cooldown(self) -> Duration {Duration(10)})]
-#[func(fn attack_cooldown(self) -> Duration {Duration(100)})]
+#[func(fn builtin_name(&self) -> &str)]
+#[func(pub fn faction(self) -> Faction {Faction::Neutral})]
+#[func(fn builtin_health(self) -> i32 {1})]
+#[func(fn builtin_attack(self) -> i32 {0})]
+#[func(fn mind(self) -> &'static [Mind] {&[Mind::Wander(Wander)]})]
+#[func(fn builtin_aggro_distance(self) -> i32 {-1})]
+#[func(fn builtin_give_up_distance(self) -> i32 {-1})]
+#[func(fn builtin_walk_cooldown(self) -> Duration {Duration(10)})]
+#[func(fn builtin_attack_cooldown(self) -> Duration {Duration(100)})]
 #[func(fn blocking(self) -> bool {false})]
 #[func(fn mortal(self) -> bool {true})]
 #[func(fn autoheal(self) -> Option<AutoHeal>)]
+#[func(fn hunger(self) -> Option<Duration>)]
+#[func(fn builtin_loot(self) -> &'static [Loot] {&[]})]
+#[func(pub fn tame_food(self) -> Option<Item>)]
+#[func(pub fn produce(self) -> Option<(Item, Duration)>)]
 pub enum CreatureType {
-	#[assoc(name = "Player")]
+	#[assoc(builtin_name = "Player")]
 	#[assoc(sprite = Sprite::PlayerDefault)]
-	#[assoc(mind = Mind::Player)]
-	#[assoc(walk_cooldown = Duration(2))]
-	#[assoc(attack_cooldown = Duration(10))]
+	#[assoc(mind = &[])]
+	#[assoc(builtin_walk_cooldown = Duration(2))]
+	#[assoc(builtin_attack_cooldown = Duration(10))]
 	#[assoc(faction = Faction::Player)]
 	#[assoc(blocking = false)]
-	#[assoc(attack = 5)]
-	#[assoc(health = 100)]
+	#[assoc(builtin_attack = 5)]
+	#[assoc(builtin_health = 100)]
 	#[assoc(mortal = false)]
-	#[assoc(autoheal = AutoHeal {cooldown: Duration(100), amount: 1})]
+	#[assoc(autoheal = AutoHeal {cooldown: Duration(100), sheltered_cooldown: Duration(20), amount: 1})]
+	#[assoc(hunger = Duration(600))]
 	Player,
-	#[assoc(name = "Frog")]
+	#[assoc(builtin_name = "Frog")]
 	#[assoc(sprite = Sprite::Frog)]
-	#[assoc(walk_cooldown = Duration(5))]
+	#[assoc(mind = &[
+		Mind::Flee(Flee {from: Faction::Player, distance: 3}),
+		Mind::Rest(Rest {during: DayPhase::Night}),
+		Mind::Herd(Herd {distance: 3, sight: 12}),
+		Mind::Wander(Wander),
+	])]
+	#[assoc(builtin_walk_cooldown = Duration(5))]
+	#[assoc(builtin_loot = &[Loot {item: Item::FrogLeg, odds: 1.0, to_killer: true}])]
 	Frog,
-	#[assoc(name = "Worm")]
+	#[assoc(builtin_name = "Worm")]
 	#[assoc(sprite = Sprite::Worm)]
-	#[assoc(mind = Mind::Aggressive)]
+	#[assoc(mind = &[
+		Mind::Hunt(Hunt),
+		Mind::Rest(Rest {during: DayPhase::Day}),
+		Mind::Wander(Wander),
+	])]
 	#[assoc(blocking = true)]
-	#[assoc(walk_cooldown = Duration(5))]
-	#[assoc(attack_cooldown = Duration(15))]
+	#[assoc(builtin_walk_cooldown = Duration(5))]
+	#[assoc(builtin_attack_cooldown = Duration(15))]
 	#[assoc(faction = Faction::Evil)]
-	#[assoc(health = 12)]
-	#[assoc(attack = 2)]
-	#[assoc(aggro_distance = 4)]
-	#[assoc(give_up_distance = 10)]
-	Worm
+	#[assoc(builtin_health = 12)]
+	#[assoc(builtin_attack = 2)]
+	#[assoc(builtin_aggro_distance = 4)]
+	#[assoc(builtin_give_up_distance = 10)]
+	#[assoc(builtin_loot = &[
+		Loot {item: Item::Slime, odds: 0.8, to_killer: true},
+		Loot {item: Item::WormHide, odds: 0.5, to_killer: false},
+	])]
+	Worm,
+	#[assoc(builtin_name = "Sheep")]
+	#[assoc(sprite = Sprite::Sheep)]
+	#[assoc(mind = &[
+		Mind::Follow(Follow {distance: 2, sight: 8}),
+		Mind::Rest(Rest {during: DayPhase::Night}),
+		Mind::Herd(Herd {distance: 2, sight: 12}),
+		Mind::Wander(Wander),
+	])]
+	#[assoc(builtin_health = 8)]
+	#[assoc(tame_food = Item::DiscLeaf)]
+	#[assoc(produce = (Item::Wool, Duration(3000)))]
+	Sheep,
+	#[assoc(builtin_name = "Hen")]
+	#[assoc(sprite = Sprite::Hen)]
+	#[assoc(mind = &[
+		Mind::Follow(Follow {distance: 2, sight: 8}),
+		Mind::Rest(Rest {during: DayPhase::Night}),
+		Mind::Wander(Wander),
+	])]
+	#[assoc(builtin_health = 3)]
+	#[assoc(builtin_walk_cooldown = Duration(6))]
+	#[assoc(tame_food = Item::GreenSeed)]
+	#[assoc(produce = (Item::Egg, Duration(1500)))]
+	Hen,
+}
+
+impl CreatureType {
+	fn name(&self) -> &str {
+		content::creature(*self)
+			.and_then(|def| def.name.as_deref())
+			.unwrap_or_else(|| self.builtin_name())
+	}
+
+	fn health(self) -> i32 {
+		content::creature(self).and_then(|def| def.health).unwrap_or_else(|| self.builtin_health())
+	}
+
+	fn attack(self) -> i32 {
+		content::creature(self).and_then(|def| def.attack).unwrap_or_else(|| self.builtin_attack())
+	}
+
+	fn aggro_distance(self) -> i32 {
+		content::creature(self).and_then(|def| def.aggro_distance).unwrap_or_else(|| self.builtin_aggro_distance())
+	}
+
+	fn give_up_distance(self) -> i32 {
+		content::creature(self).and_then(|def| def.give_up_distance).unwrap_or_else(|| self.builtin_give_up_distance())
+	}
+
+	fn walk_cooldown(self) -> Duration {
+		content::creature(self).and_then(|def| def.walk_cooldown).unwrap_or_else(|| self.builtin_walk_cooldown())
+	}
+
+	fn attack_cooldown(self) -> Duration {
+		content::creature(self).and_then(|def| def.attack_cooldown).unwrap_or_else(|| self.builtin_attack_cooldown())
+	}
+
+	fn loot(self) -> Vec<Loot> {
+		content::creature(self).and_then(|def| def.loot.clone()).unwrap_or_else(|| self.builtin_loot().to_vec())
+	}
 }
 
 
 #[derive(Debug, Clone)]
 struct AutoHeal {
 	cooldown: Duration,
+	/// Used instead of the cooldown on safe ground
+	sheltered_cooldown: Duration,
 	amount: i32,
 }
 
-#[derive(Debug, Clone)]
-pub enum Mind {
-	Player,
-	Idle,
-	Aggressive
+/// An item that a creature may leave behind when it dies
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Loot {
+	pub item: Item,
+	#[serde(default="certain")]
+	pub odds: f32,
+	/// Whether the item goes straight into the killer's inventory instead of dropping on the ground
+	#[serde(default)]
+	pub to_killer: bool,
 }
+fn certain() -> f32 {1.0}
 
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::cell::RefCell;
+
+	#[test]
+	fn frog_always_drops_leg() {
+		let frog = Creature::spawn_npc(CreatureId::Spawned(SpawnId(Pos::new(2, 3))), Pos::new(2, 3), CreatureType::Frog);
+		for t in 0..20 {
+			assert_eq!(frog.roll_loot(Timestamp(t)).iter().map(|loot| loot.item).collect::<Vec<_>>(), vec![Item::FrogLeg]);
+		}
+	}
+
+	#[test]
+	fn starving_players_eat_to_recover() {
+		let player = CreatureId::Player(crate::player::PlayerId::create("tester").unwrap());
+		let mut creature = Creature::spawn_npc(player, Pos::new(0, 0), CreatureType::Player);
+		creature.energy = 1;
+		creature.health = 50;
+		creature.update(Timestamp(600), false);
+		assert!(creature.is_starving());
+		assert_eq!(creature.walk_cooldown(), Duration(CreatureType::Player.walk_cooldown().0 * STARVING_SLOWDOWN));
+		creature.update(Timestamp(700), false);
+		assert_eq!(creature.health, 50);
+		creature.inventory.add(Item::Stone);
+		creature.inventory.add(Item::RoastFrogLeg);
+		assert!(!creature.eat(0));
+		assert!(creature.eat(1));
+		assert_eq!(creature.energy, Item::RoastFrogLeg.food());
+		assert_eq!(creature.inventory.get_item(1), None);
+		assert!(!creature.is_starving());
+	}
+
+	#[test]
+	fn worm_loot_is_random() {
+		let worm = Creature::spawn_npc(CreatureId::Spawned(SpawnId(Pos::new(2, 3))), Pos::new(2, 3), CreatureType::Worm);
+		let hides = (0..100).filter(|t| worm.roll_loot(Timestamp(*t)).iter().any(|loot| loot.item == Item::WormHide)).count();
+		assert!(hides > 20 && hides < 80, "{} hides", hides);
+	}
+
+	#[test]
+	fn armour_and_weapons_change_damage() {
+		let player = CreatureId::Player(crate::player::PlayerId::create("tester").unwrap());
+		let mut attacker = Creature::spawn_npc(player, Pos::new(0, 0), CreatureType::Player);
+		let defender = RefCell::new(Creature::spawn_npc(CreatureId::Spawned(SpawnId(Pos::new(1, 0))), Pos::new(1, 0), CreatureType::Worm));
+		defender.borrow_mut().health = 100;
+		attacker.attack(defender.borrow_mut(), Timestamp(0));
+		assert_eq!(defender.borrow().health, 100 - CreatureType::Player.attack());
+		attacker.inventory.add(Item::Axe);
+		attacker.equip(0);
+		defender.borrow_mut().inventory.add(Item::HideVest);
+		defender.borrow_mut().equip(0);
+		attacker.attack(defender.borrow_mut(), Timestamp(1));
+		let axe = Item::Axe.weapon().unwrap();
+		assert_eq!(defender.borrow().health, 100 - CreatureType::Player.attack() - (axe.damage - Item::HideVest.armour()));
+		assert_eq!(attacker.combat_stats().cooldown, axe.cooldown);
+	}
+
+	#[test]
+	fn pvp_policy_decides_player_fights() {
+		let player = |name: &str, pos: Pos, pvp: bool| {
+			let mut creature = Creature::spawn_npc(CreatureId::Player(crate::player::PlayerId::create(name).unwrap()), pos, CreatureType::Player);
+			creature.pvp = pvp;
+			CreatureTile::new(&creature)
+		};
+		let alice = player("alice", Pos::new(100, 100), true);
+		let bob = player("bob", Pos::new(101, 100), false);
+		let carol = player("carol", Pos::new(100, 101), true);
+		assert!(!PvpPolicy::Off.allows(&alice, &carol));
+		assert!(PvpPolicy::On.allows(&alice, &bob));
+		assert!(!PvpPolicy::On.allows(&alice, &alice));
+		assert!(!PvpPolicy::OptIn.allows(&alice, &bob));
+		assert!(PvpPolicy::OptIn.allows(&alice, &carol));
+		assert_eq!(serde_json::from_str::<PvpPolicy>(r#""outside-sanctuary""#).unwrap(), PvpPolicy::On);
+	}
+
+	#[test]
+	fn tame_creatures_produce_on_their_own_schedule() {
+		let owner = crate::player::PlayerId::create("shepherd").unwrap();
+		let mut early = Creature::spawn_npc(CreatureId::Bred(0), Pos::new(0, 0), CreatureType::Hen);
+		let mut late = Creature::spawn_npc(CreatureId::Bred(1), Pos::new(1, 0), CreatureType::Hen);
+		assert_eq!(early.produce(Timestamp(5000)), None);
+		early.tame(owner, Timestamp(0));
+		late.tame(owner, Timestamp(700));
+		assert_eq!(early.produce(Timestamp(1499)), None);
+		assert_eq!(early.produce(Timestamp(1500)), Some(Item::Egg));
+		assert_eq!(late.produce(Timestamp(1500)), None);
+		assert_eq!(late.produce(Timestamp(2200)), Some(Item::Egg));
+		assert_eq!(late.produce(Timestamp(2201)), None);
+	}
+
+	#[test]
+	fn knocked_out_players_respawn() {
+		let mut player = Creature::spawn_npc(CreatureId::Player(crate::player::PlayerId::create("alice").unwrap()), Pos::new(50, 50), CreatureType::Player);
+		player.burn(CreatureType::Player.health(), Timestamp(0));
+		assert!(!player.update(Timestamp(1), false));
+		assert!(player.is_knocked_out());
+		player.respawn(Pos::new(0, 0));
+		assert!(!player.is_knocked_out());
+		assert_eq!(player.pos, Pos::new(0, 0));
+	}
+
+	#[test]
+	fn players_can_hunt_neutral_creatures() {
+		assert!(Faction::Player.can_attack(Faction::Neutral));
+		assert!(!Faction::Neutral.can_attack(Faction::Player));
+		assert!(!Faction::Evil.can_attack(Faction::Neutral));
+	}
+}
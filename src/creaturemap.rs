@@ -4,7 +4,8 @@ use core::cell::Ref;
 
 use crate::{
 	pos::Pos,
-	creature::{Creature, Faction},
+	creature::{Creature, CreatureType, Faction},
+	player::PlayerId,
 	creatures::CreatureId,
 };
 
@@ -67,8 +68,11 @@ impl CreatureMap {
 pub struct CreatureTile {
 	pub id: CreatureId,
 	pub faction: Faction,
+	pub kind: CreatureType,
 	pub blocking: bool,
+	pub pvp: bool,
 	pub pos: Pos,
+	pub owner: Option<PlayerId>,
 }
 
 impl CreatureTile {
@@ -77,7 +81,10 @@ impl CreatureTile {
 			id: creature.id,
 			pos: creature.pos,
 			faction: creature.faction(),
+			kind: creature.kind(),
 			blocking: creature.blocking(),
+			pvp: creature.wants_pvp(),
+			owner: creature.owner(),
 		}
 	}
 }
@@ -1,24 +1,25 @@
 
 use std::collections::{HashMap};
 use std::cell::{RefCell, Ref, RefMut};
-use serde::{Serialize, Serializer};
+use serde::{Serialize, Deserialize, Serializer, Deserializer, de};
 
 use crate::{
 	player::{PlayerId, PlayerConfig, PlayerConfigMsg},
 	controls::{Control},
 	pos::Pos,
-	creature::{Creature, PlayerSave, CreatureType as Npc},
+	creature::{Creature, PlayerSave, NpcSave, CreatureType as Npc},
 	loadedareas::LoadedAreas,
 	timestamp::{Timestamp, Duration},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SpawnId(pub Pos);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CreatureId {
 	Player(PlayerId),
 	Spawned(SpawnId),
+	Bred(u64),
 }
 
 impl CreatureId {
@@ -37,14 +38,38 @@ impl Serialize for CreatureId {
 		match self {
 			Self::Player(id) => format!("p-{}", id.name()),
 			Self::Spawned(SpawnId(Pos{x, y})) => format!("s-{},{}", x, y),
+			Self::Bred(n) => format!("b-{}", n),
 		}.serialize(serializer)
 	}
 }
+impl<'de> Deserialize<'de> for CreatureId {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		let text = <&str>::deserialize(deserializer)?;
+		if let Some(name) = text.strip_prefix("p-") {
+			return Ok(Self::Player(PlayerId::create(name).map_err(de::Error::custom)?));
+		}
+		if let Some(n) = text.strip_prefix("b-") {
+			return Ok(Self::Bred(n.parse().map_err(de::Error::custom)?));
+		}
+		let (x, y) = text.strip_prefix("s-")
+			.and_then(|coords| coords.split_once(','))
+			.ok_or_else(|| de::Error::custom(format!("invalid creature id {}", text)))?;
+		let x = x.parse().map_err(de::Error::custom)?;
+		let y = y.parse().map_err(de::Error::custom)?;
+		Ok(Self::Spawned(SpawnId(Pos{x, y})))
+	}
+}
 
 #[derive(Debug)]
 pub struct Creatures {
 	players: HashMap<PlayerId, Player>,
 	spawned_creatures: HashMap<SpawnId, SpawnedCreature>,
+	/// Creatures that don't belong to a spawn point because they were bred or tamed
+	bred_creatures: HashMap<u64, SpawnedCreature>,
+	next_bred: u64,
+	/// The health of spawned creatures that were unloaded while wounded, so they come back with it
+	wounds: HashMap<SpawnId, i32>,
 }
 
 impl Creatures {
@@ -53,6 +78,9 @@ impl Creatures {
 		Self {
 			players: HashMap::new(),
 			spawned_creatures: HashMap::new(),
+			bred_creatures: HashMap::new(),
+			next_bred: 0,
+			wounds: HashMap::new(),
 		}
 	}
 
@@ -110,12 +138,16 @@ impl Creatures {
 		self.players.values()
 			.map(|player| &player.body)
 			.chain(
-				self.spawned_creatures.values()
+				self.npcs()
 				.filter(|s| !s.body.borrow().is_dead())
 				.map(|s| &s.body)
 			)
 	}
 
+	fn npcs(&self) -> impl Iterator<Item=&SpawnedCreature> {
+		self.spawned_creatures.values().chain(self.bred_creatures.values())
+	}
+
 	pub fn all_mut(&self) -> impl Iterator<Item=RefMut<Creature>> {
 		self.iter_cell().map(|body| body.borrow_mut())
 	}
@@ -128,6 +160,7 @@ impl Creatures {
 		match id {
 			CreatureId::Player(player_id) => self.players.get(player_id).map(|player| &player.body),
 			CreatureId::Spawned(spawn_id) => self.spawned_creatures.get(spawn_id).map(|s| &s.body),
+			CreatureId::Bred(n) => self.bred_creatures.get(n).map(|s| &s.body),
 		}
 	}
 
@@ -140,7 +173,7 @@ impl Creatures {
 	}
 
 	pub fn dead(&self) -> impl Iterator<Item=Ref<Creature>> {
-		self.spawned_creatures.values()
+		self.npcs()
 			.filter(|s| s.body.borrow().is_dead())
 			.map(|s| s.body.borrow())
 	}
@@ -151,19 +184,102 @@ impl Creatures {
 			return;
 		}
 		// println!("spawning {:?} npc at {:?}", npc, spawn_id);
-		let body = Creature::spawn_npc(CreatureId::Spawned(id), pos, npc);
+		let mut body = Creature::spawn_npc(CreatureId::Spawned(id), pos, npc);
+		if let Some(health) = self.wounds.remove(&id) {
+			body.set_health(health);
+		}
 		let spawned_creature = SpawnedCreature { body: RefCell::new(body), last_load: Timestamp(0) };
 		self.spawned_creatures.insert(id, spawned_creature);
 	}
 
+	/// Add a creature that was born rather than spawned by the map.
+	/// Bred creatures are numbered so their ids never clash with spawn points.
+	pub fn add_offspring(&mut self, pos: Pos, npc: Npc, owner: PlayerId, time: Timestamp) -> CreatureId {
+		let n = self.next_bred();
+		let id = CreatureId::Bred(n);
+		let mut body = Creature::spawn_npc(id, pos, npc);
+		body.tame(owner, time);
+		self.bred_creatures.insert(n, SpawnedCreature { body: RefCell::new(body), last_load: Timestamp(0) });
+		id
+	}
+
+	/// Give a creature to a player.
+	/// A tamed creature leaves its spawn point so the spawn point can spawn a new one.
+	pub fn tame(&mut self, id: &CreatureId, owner: PlayerId, time: Timestamp) -> Option<CreatureId> {
+		let CreatureId::Spawned(spawn_id) = id else {
+			self.get_creature_mut(id)?.tame(owner, time);
+			return Some(*id);
+		};
+		let mut spawned = self.spawned_creatures.remove(spawn_id)?;
+		let n = self.next_bred();
+		let body = spawned.body.get_mut();
+		body.id = CreatureId::Bred(n);
+		body.tame(owner, time);
+		self.bred_creatures.insert(n, spawned);
+		Some(CreatureId::Bred(n))
+	}
+
+	fn next_bred(&mut self) -> u64 {
+		let n = self.next_bred;
+		self.next_bred += 1;
+		n
+	}
+
+	/// The number of creatures bred so far, which is saved so new ids stay unique
+	pub fn bred_count(&self) -> u64 {
+		self.next_bred
+	}
+
+	pub fn save_spawned(&self) -> Vec<NpcSave> {
+		self.npcs()
+			.map(|spawned| spawned.body.borrow().save_npc())
+			.collect()
+	}
+
+	pub fn load_spawned(&mut self, saved: Vec<NpcSave>, bred_count: u64, time: Timestamp) {
+		self.next_bred = bred_count;
+		for npc in saved {
+			let id = npc.id();
+			let spawned = SpawnedCreature { body: RefCell::new(Creature::load_npc(npc)), last_load: time };
+			match id {
+				CreatureId::Spawned(spawn_id) => {
+					self.spawned_creatures.insert(spawn_id, spawned);
+				}
+				CreatureId::Bred(n) => {
+					self.next_bred = self.next_bred.max(n + 1);
+					self.bred_creatures.insert(n, spawned);
+				}
+				CreatureId::Player(_) => {}
+			}
+		}
+	}
+
+	pub fn save_wounds(&self) -> Vec<(SpawnId, i32)> {
+		self.wounds.iter().map(|(id, health)| (*id, *health)).collect()
+	}
+
+	pub fn load_wounds(&mut self, saved: Vec<(SpawnId, i32)>) {
+		self.wounds.extend(saved);
+	}
+
 	pub fn despawn(&mut self, loaded_areas: &LoadedAreas, time: Timestamp) {
-		for spawned in self.spawned_creatures.values_mut() {
+		for spawned in self.spawned_creatures.values_mut().chain(self.bred_creatures.values_mut()) {
 			let body = spawned.body.borrow();
-			if !body.is_dead() && loaded_areas.is_loaded(body.pos) {
+			// tame creatures stay around until their owner comes back for them
+			if !body.is_dead() && (loaded_areas.is_loaded(body.pos) || body.owner().is_some()) {
 				spawned.last_load = time;
 			}
 		}
-		self.spawned_creatures.retain(|_spawn_id, spawned| spawned.last_load > time - Duration(500));
+		let wounds = &mut self.wounds;
+		self.spawned_creatures.retain(|spawn_id, spawned| {
+			let keep = spawned.last_load > time - Duration(500);
+			let body = spawned.body.borrow();
+			if !keep && !body.is_dead() && body.is_wounded() {
+				wounds.insert(*spawn_id, body.health());
+			}
+			keep
+		});
+		self.bred_creatures.retain(|_n, spawned| spawned.last_load > time - Duration(500));
 	}
 }
 
@@ -196,3 +312,88 @@ pub struct PlayerNotFound(pub PlayerId);
 pub struct PlayerAlreadyExists(pub PlayerId);
 #[derive(Debug)]
 pub struct CreatureNotFound(pub CreatureId);
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn creature_id_roundtrip() {
+		for id in [CreatureId::Player(PlayerId::create("alice").unwrap()), CreatureId::Spawned(SpawnId(Pos::new(-3, 12))), CreatureId::Bred(7)] {
+			let json = serde_json::to_string(&id).unwrap();
+			assert_eq!(serde_json::from_str::<CreatureId>(&json).unwrap(), id);
+		}
+	}
+
+	#[test]
+	fn spawned_creatures_survive_reload() {
+		let mut creatures = Creatures::new();
+		creatures.spawn(Pos::new(4, 5), Npc::Frog);
+		let id = CreatureId::Spawned(SpawnId(Pos::new(4, 5)));
+		creatures.get_creature_mut(&id).unwrap().pos = Pos::new(6, 5);
+		let saved: Vec<NpcSave> = serde_json::from_str(&serde_json::to_string(&creatures.save_spawned()).unwrap()).unwrap();
+		let mut loaded = Creatures::new();
+		loaded.load_spawned(saved, 0, Timestamp(10));
+		assert_eq!(loaded.get_creature(&id).unwrap().pos, Pos::new(6, 5));
+		loaded.spawn(Pos::new(4, 5), Npc::Frog);
+		assert_eq!(loaded.all().count(), 1);
+	}
+
+	#[test]
+	fn wounds_and_deaths_are_remembered() {
+		let mut creatures = Creatures::new();
+		creatures.spawn(Pos::new(0, 0), Npc::Worm);
+		let id = CreatureId::Spawned(SpawnId(Pos::new(0, 0)));
+		let full_health = creatures.get_creature(&id).unwrap().health();
+		creatures.get_creature_mut(&id).unwrap().burn(3, Timestamp(1));
+		creatures.despawn(&LoadedAreas::new(), Timestamp(1000));
+		assert_eq!(creatures.all().count(), 0);
+		let wounds = serde_json::from_str(&serde_json::to_string(&creatures.save_wounds()).unwrap()).unwrap();
+		let mut creatures = Creatures::new();
+		creatures.load_wounds(wounds);
+		creatures.spawn(Pos::new(0, 0), Npc::Worm);
+		assert_eq!(creatures.get_creature(&id).unwrap().health(), full_health - 3);
+		creatures.get_creature_mut(&id).unwrap().burn(100, Timestamp(1001));
+		creatures.get_creature_mut(&id).unwrap().update(Timestamp(1002), false);
+		let saved: Vec<NpcSave> = serde_json::from_str(&serde_json::to_string(&creatures.save_spawned()).unwrap()).unwrap();
+		let mut loaded = Creatures::new();
+		loaded.load_spawned(saved, 0, Timestamp(1002));
+		loaded.spawn(Pos::new(0, 0), Npc::Worm);
+		assert_eq!(loaded.all().count(), 0);
+		loaded.despawn(&LoadedAreas::new(), Timestamp(1600));
+		loaded.spawn(Pos::new(0, 0), Npc::Worm);
+		assert_eq!(loaded.all().count(), 1);
+	}
+
+	#[test]
+	fn taming_frees_the_spawn_point() {
+		let owner = PlayerId::create("shepherd").unwrap();
+		let mut creatures = Creatures::new();
+		creatures.spawn(Pos::new(0, 0), Npc::Sheep);
+		let wild = CreatureId::Spawned(SpawnId(Pos::new(0, 0)));
+		let tame = creatures.tame(&wild, owner, Timestamp(0)).unwrap();
+		assert_eq!(tame, CreatureId::Bred(0));
+		assert_eq!(creatures.get_creature(&tame).unwrap().id, tame);
+		assert!(creatures.get_creature(&wild).is_none());
+		creatures.spawn(Pos::new(0, 0), Npc::Sheep);
+		assert_eq!(creatures.all().count(), 2);
+	}
+
+	#[test]
+	fn tame_creatures_keep_their_owner() {
+		let owner = PlayerId::create("shepherd").unwrap();
+		let mut creatures = Creatures::new();
+		creatures.spawn(Pos::new(0, 0), Npc::Sheep);
+		let lamb = creatures.add_offspring(Pos::new(0, 0), Npc::Sheep, owner, Timestamp(0));
+		assert_eq!(lamb, CreatureId::Bred(0));
+		creatures.despawn(&LoadedAreas::new(), Timestamp(1000));
+		let saved: Vec<NpcSave> = serde_json::from_str(&serde_json::to_string(&creatures.save_spawned()).unwrap()).unwrap();
+		let mut loaded = Creatures::new();
+		loaded.load_spawned(saved, creatures.bred_count(), Timestamp(1000));
+		assert_eq!(loaded.all().count(), 1);
+		assert_eq!(loaded.get_creature(&lamb).unwrap().owner(), Some(owner));
+		loaded.spawn(Pos::new(0, 0), Npc::Sheep);
+		assert_eq!(loaded.add_offspring(Pos::new(0, 0), Npc::Sheep, owner, Timestamp(1000)), CreatureId::Bred(1));
+	}
+}
@@ -1,50 +1,71 @@
 
 
 
+use std::fmt;
 use serde::{Serialize, Deserialize};
 use enum_assoc::Assoc;
 use crate::{
 	sprite::Sprite,
 	action::{InteractionType, Interactable},
 	tile::Structure,
+	item::Item,
+	content,
+	weather::Weather,
+	timestamp::Season::{self, *},
+	random,
 };
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Assoc, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Assoc, Serialize, Deserialize)]
 #[func(fn sprite(self) -> Sprite)]
-#[func(fn describe(&self) -> &str)]
+#[func(fn builtin_describe(&self) -> &str)]
 #[func(fn interactions(self) -> Vec<Interactable> {Vec::new()})]
-#[func(fn next(self) -> Option<(i64, CropType)>)]
-#[func(fn grow(self) -> Option<(i64, Structure)>)]
-#[func(fn fertilized_grow(self) -> Option<CropType>)]
+#[func(fn builtin_next(self) -> Option<(i64, CropType)>)]
+#[func(fn builtin_grow(self) -> Option<(i64, Structure)>)]
+#[func(fn builtin_fertilized_grow(self) -> Option<CropType>)]
+#[func(fn builtin_regrow(self) -> Option<CropType>)]
 #[func(fn inosculate(self) -> Vec<(CropType, CropType)> {Vec::new()})]
-enum CropType {
+#[func(fn builtin_seasons(self) -> &'static [Season] {&[Spring, Summer, Autumn]})]
+pub enum CropType {
 	
 	#[assoc(sprite = Sprite::PlantedSeed)]
-	#[assoc(describe = "Planted seed")]
-	#[assoc(next = (1, CropType::GreenSeedling))]
+	#[assoc(builtin_describe = "Planted seed")]
+	#[assoc(builtin_next = (1, CropType::GreenSeedling))]
+	#[assoc(builtin_seasons = &[Spring, Summer])]
 	GreenSeed,
 	
 	#[assoc(sprite = Sprite::Seedling)]
-	#[assoc(describe = "Seedling")]
-	#[assoc(next = (1, CropType::YoungDiscPlant))]
+	#[assoc(builtin_describe = "Seedling")]
+	#[assoc(builtin_next = (1, CropType::YoungDiscPlant))]
+	#[assoc(builtin_seasons = &[Spring, Summer])]
 	GreenSeedling,
 	
 	#[assoc(sprite = Sprite::YoungDiscPlant)]
-	#[assoc(describe = "A small plant with big round leaves")]
-	#[assoc(next = (1, CropType::DiscPlant))]
-	#[assoc(fertilized_grow = CropType::DiscShoot)]
+	#[assoc(builtin_describe = "A small plant with big round leaves")]
+	#[assoc(builtin_next = (1, CropType::DiscPlant))]
+	#[assoc(builtin_seasons = &[Spring, Summer])]
+	#[assoc(builtin_fertilized_grow = CropType::DiscShoot)]
 	YoungDiscPlant,
 	
 	#[assoc(sprite = Sprite::DiscPlant)]
-	#[assoc(describe = "A plant with big round leaves")]
-	#[assoc(grow = (1, Structure::SeedingDiscPlant))]
-	#[assoc(fertilized_grow = CropType::DiscShoot)]
+	#[assoc(builtin_describe = "A plant with big round leaves")]
+	#[assoc(builtin_next = (1, CropType::SeedingDiscPlant))]
+	#[assoc(builtin_fertilized_grow = CropType::DiscShoot)]
 	DiscPlant,
 	
+	#[assoc(sprite = Sprite::SeedingDiscPlant)]
+	#[assoc(builtin_describe = "Seeding Disc plant")]
+	#[assoc(interactions = vec![
+		Interactable::harvest(InteractionType::Cut, 2, &[1.0], &[Item::GreenSeed, Item::GreenSeed, Item::GreenSeed, Item::GreenSeed]),
+		Interactable::harvest(InteractionType::Cut, 1, &[1.0], &[Item::GreenSeed, Item::GreenSeed, Item::GreenSeed]),
+		Interactable::take(&[Item::GreenSeed, Item::GreenSeed])
+	])]
+	#[assoc(builtin_regrow = CropType::DiscPlant)]
+	SeedingDiscPlant,
+	
 	#[assoc(sprite = Sprite::DiscShoot)]
-	#[assoc(describe = "A shoot of a plant with big round leaves")]
-	#[assoc(grow = (1, Structure::DiscLeaf))]
+	#[assoc(builtin_describe = "A shoot of a plant with big round leaves")]
+	#[assoc(builtin_grow = (1, Structure::DiscLeaf))]
 	#[assoc(inosculate = vec![
 		(CropType::HardShoot, CropType::HardDiscPlant),
 		(CropType::KnifeShoot, CropType::DiscKnifePlant)
@@ -53,30 +74,42 @@ enum CropType {
 	
 	
 	#[assoc(sprite = Sprite::PlantedSeed)]
-	#[assoc(describe = "Planted seed")]
-	#[assoc(next = (1, CropType::YellowSeedling))]
+	#[assoc(builtin_describe = "Planted seed")]
+	#[assoc(builtin_next = (1, CropType::YellowSeedling))]
+	#[assoc(builtin_seasons = &[Summer, Autumn])]
 	YellowSeed,
 	
 	#[assoc(sprite = Sprite::Seedling)]
-	#[assoc(describe = "Seedling")]
-	#[assoc(next = (1, CropType::YoungKnifePlant))]
+	#[assoc(builtin_describe = "Seedling")]
+	#[assoc(builtin_next = (1, CropType::YoungKnifePlant))]
+	#[assoc(builtin_seasons = &[Summer, Autumn])]
 	YellowSeedling,
 	
 	#[assoc(sprite = Sprite::YoungKnifePlant)]
-	#[assoc(describe = "A small plant with sharp leaves")]
-	#[assoc(next = (1, CropType::KnifePlant))]
-	#[assoc(fertilized_grow = CropType::KnifeShoot)]
+	#[assoc(builtin_describe = "A small plant with sharp leaves")]
+	#[assoc(builtin_next = (1, CropType::KnifePlant))]
+	#[assoc(builtin_seasons = &[Summer, Autumn])]
+	#[assoc(builtin_fertilized_grow = CropType::KnifeShoot)]
 	YoungKnifePlant,
 	
 	#[assoc(sprite = Sprite::KnifePlant)]
-	#[assoc(describe = "A plant with sharp leaves")]
-	#[assoc(grow = (1, Structure::SeedingKnifePlant))]
-	#[assoc(fertilized_grow = CropType::KnifeShoot)]
+	#[assoc(builtin_describe = "A plant with sharp leaves")]
+	#[assoc(builtin_next = (1, CropType::SeedingKnifePlant))]
+	#[assoc(builtin_fertilized_grow = CropType::KnifeShoot)]
 	KnifePlant,
 	
+	#[assoc(sprite = Sprite::SeedingKnifePlant)]
+	#[assoc(builtin_describe = "Seeding Knife plant")]
+	#[assoc(interactions = vec![
+		Interactable::harvest(InteractionType::Cut, 2, &[1.0], &[Item::YellowSeed, Item::YellowSeed, Item::YellowSeed, Item::YellowSeed]),
+		Interactable::harvest(InteractionType::Cut, 1, &[1.0], &[Item::YellowSeed, Item::YellowSeed, Item::YellowSeed]),
+		Interactable::take(&[Item::YellowSeed, Item::YellowSeed])
+	])]
+	SeedingKnifePlant,
+	
 	#[assoc(sprite = Sprite::KnifeShoot)]
-	#[assoc(describe = "A shoot of a plant with sharp leaves")]
-	#[assoc(grow = (1, Structure::KnifeLeaf))]
+	#[assoc(builtin_describe = "A shoot of a plant with sharp leaves")]
+	#[assoc(builtin_grow = (1, Structure::KnifeLeaf))]
 	#[assoc(inosculate = vec![
 		(CropType::DiscShoot, CropType::DiscKnifePlant),
 		(CropType::HardShoot, CropType::HardKnifePlant)
@@ -85,30 +118,39 @@ enum CropType {
 	
 	
 	#[assoc(sprite = Sprite::PlantedSeed)]
-	#[assoc(describe = "Planted seed")]
-	#[assoc(next = (1, CropType::BrownSeedling))]
+	#[assoc(builtin_describe = "Planted seed")]
+	#[assoc(builtin_next = (1, CropType::BrownSeedling))]
 	BrownSeed,
 	
 	#[assoc(sprite = Sprite::Seedling)]
-	#[assoc(describe = "Seedling")]
-	#[assoc(next = (1, CropType::YoungHardPlant))]
+	#[assoc(builtin_describe = "Seedling")]
+	#[assoc(builtin_next = (1, CropType::YoungHardPlant))]
 	BrownSeedling,
 	
 	#[assoc(sprite = Sprite::YoungHardPlant)]
-	#[assoc(describe = "A small plant with a hard stem")]
-	#[assoc(next = (1, CropType::HardPlant))]
-	#[assoc(fertilized_grow = CropType::HardShoot)]
+	#[assoc(builtin_describe = "A small plant with a hard stem")]
+	#[assoc(builtin_next = (1, CropType::HardPlant))]
+	#[assoc(builtin_fertilized_grow = CropType::HardShoot)]
 	YoungHardPlant,
 	
 	#[assoc(sprite = Sprite::HardPlant)]
-	#[assoc(describe = "Plant with a very hard stem")]
-	#[assoc(grow = (1, Structure::SeedingHardPlant))]
-	#[assoc(fertilized_grow = CropType::HardShoot)]
+	#[assoc(builtin_describe = "Plant with a very hard stem")]
+	#[assoc(builtin_next = (1, CropType::SeedingHardPlant))]
+	#[assoc(builtin_fertilized_grow = CropType::HardShoot)]
 	HardPlant,
 	
+	#[assoc(sprite = Sprite::SeedingHardPlant)]
+	#[assoc(builtin_describe = "Seeding Hardwood")]
+	#[assoc(interactions = vec![
+		Interactable::harvest(InteractionType::Cut, 2, &[1.0], &[Item::BrownSeed, Item::BrownSeed, Item::BrownSeed, Item::BrownSeed]),
+		Interactable::harvest(InteractionType::Cut, 1, &[1.0], &[Item::BrownSeed, Item::BrownSeed, Item::BrownSeed]),
+		Interactable::take(&[Item::BrownSeed, Item::BrownSeed])
+	])]
+	SeedingHardPlant,
+	
 	#[assoc(sprite = Sprite::HardShoot)]
-	#[assoc(describe = "A shoot of a plant with hard branches")]
-	#[assoc(grow = (1, Structure::HardwoodStick))]
+	#[assoc(builtin_describe = "A shoot of a plant with hard branches")]
+	#[assoc(builtin_grow = (1, Structure::HardwoodStick))]
 	#[assoc(inosculate = vec![
 		(CropType::DiscShoot, CropType::HardDiscPlant),
 		(CropType::KnifeShoot, CropType::HardKnifePlant)
@@ -116,74 +158,288 @@ enum CropType {
 	HardShoot,
 	
 	#[assoc(sprite = Sprite::HardKnifePlant)]
-	#[assoc(describe = "A shoot of a hardwood plant inosculated with a shoot of a knife plant")]
-	#[assoc(grow = (1, Structure::HardwoodKnife))]
+	#[assoc(builtin_describe = "A shoot of a hardwood plant inosculated with a shoot of a knife plant")]
+	#[assoc(builtin_grow = (1, Structure::HardwoodKnife))]
 	HardKnifePlant,
 	
 	#[assoc(sprite = Sprite::HardDiscPlant)]
-	#[assoc(describe = "A shoot of a hardwood plant inosculated with a shoot of a disc plant")]
-	#[assoc(grow = (1, Structure::HardwoodTable))]
+	#[assoc(builtin_describe = "A shoot of a hardwood plant inosculated with a shoot of a disc plant")]
+	#[assoc(builtin_grow = (1, Structure::HardwoodTable))]
 	HardDiscPlant,
 	
 	#[assoc(sprite = Sprite::DiscKnifePlant)]
-	#[assoc(describe = "A shoot of a knife plant inosculated with a shoot of a disc plant")]
-	#[assoc(grow = (1, Structure::SawBlade))]
+	#[assoc(builtin_describe = "A shoot of a knife plant inosculated with a shoot of a disc plant")]
+	#[assoc(builtin_grow = (1, Structure::SawBlade))]
 	DiscKnifePlant,
 }
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl CropType {
+	fn describe(&self) -> &str {
+		content::crop(*self)
+			.and_then(|def| def.description.as_deref())
+			.unwrap_or_else(|| self.builtin_describe())
+	}
+
+	fn next(self) -> Option<(i64, CropType)> {
+		content::crop(self)
+			.and_then(|def| def.next)
+			.or_else(|| self.builtin_next())
+	}
+
+	fn grow(self) -> Option<(i64, Structure)> {
+		content::crop(self)
+			.and_then(|def| def.grow)
+			.or_else(|| self.builtin_grow())
+	}
+
+	fn fertilized_grow(self) -> Option<CropType> {
+		content::crop(self)
+			.and_then(|def| def.fertilized_grow)
+			.or_else(|| self.builtin_fertilized_grow())
+	}
+
+	/// The stage that perennial crops go back to after harvest
+	fn regrow(self) -> Option<CropType> {
+		content::crop(self)
+			.and_then(|def| def.regrow)
+			.or_else(|| self.builtin_regrow())
+	}
+
+	fn seasons(self) -> Vec<Season> {
+		content::crop(self)
+			.and_then(|def| def.seasons.clone())
+			.unwrap_or_else(|| self.builtin_seasons().to_vec())
+	}
+
+	fn grows(self) -> bool {
+		self.next().is_some() || self.grow().is_some()
+	}
+}
+
+
+/// The highest level a trait can reach
+const MAX_TRAIT: u8 = 3;
+/// The chance in percent that a trait changes when it is passed on
+const MUTATION_CHANCE: u32 = 10;
+
+/// Heritable traits of a crop, packed in two bits each
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Genes(u8);
+
+impl Genes {
+	const SPEED: u8 = 0;
+	const YIELD: u8 = 2;
+	const HARDINESS: u8 = 4;
+	const TRAITS: [u8; 3] = [Self::SPEED, Self::YIELD, Self::HARDINESS];
+
+	fn get(self, shift: u8) -> u8 {
+		(self.0 >> shift) & MAX_TRAIT
+	}
+
+	fn with(self, shift: u8, level: u8) -> Self {
+		Self((self.0 & !(MAX_TRAIT << shift)) | (level.min(MAX_TRAIT) << shift))
+	}
+
+	/// How much faster the crop grows
+	pub fn speed(self) -> u8 {
+		self.get(Self::SPEED)
+	}
+
+	/// How likely harvests give extra items
+	pub fn yields(self) -> u8 {
+		self.get(Self::YIELD)
+	}
+
+	/// How well the crop stands drought and cold
+	pub fn hardiness(self) -> u8 {
+		self.get(Self::HARDINESS)
+	}
+
+	/// Every trait comes from one of the parents and might mutate
+	pub fn cross(self, other: Self, rind: u32) -> Self {
+		self.inherit(other, rind).mutate(rind)
+	}
+
+	fn inherit(self, other: Self, rind: u32) -> Self {
+		let mut child = Self::default();
+		for (i, shift) in Self::TRAITS.into_iter().enumerate() {
+			let seed = random::randomize_u32(rind ^ (i as u32 * 7919));
+			child = child.with(shift, if seed & 1 == 0 { self.get(shift) } else { other.get(shift) });
+		}
+		child
+	}
+
+	fn mutate(self, rind: u32) -> Self {
+		let mut mutant = self;
+		for (i, shift) in Self::TRAITS.into_iter().enumerate() {
+			let seed = random::randomize_u32(rind ^ (i as u32 * 7919));
+			if random::percentage(seed, MUTATION_CHANCE) {
+				let level = self.get(shift);
+				mutant = mutant.with(shift, if seed & 2 == 0 { level.saturating_sub(1) } else { level + 1 });
+			}
+		}
+		mutant
+	}
+}
+
+impl fmt::Display for Genes {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let traits: Vec<String> = [(self.speed(), "fast"), (self.yields(), "bountiful"), (self.hardiness(), "hardy")]
+			.into_iter()
+			.filter(|(level, _)| *level > 0)
+			.map(|(level, name)| format!("{}{}", ["", "", "very ", "extremely "][level as usize], name))
+			.collect();
+		write!(f, "{}", traits.join(", "))
+	}
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Crop {
 	typ: CropType,
-	flags: u8
+	flags: u8,
+	#[serde(default)]
+	genes: Genes,
 }
 
 const WATERED: u8 = 1<<7;
 const FERTILIZED: u8 = 1<<6;
+/// A disease that stops the crop from growing, spreads and eventually kills it
+const BLIGHTED: u8 = 1<<5;
+/// Pests that eat part of the harvest and spread to crops of the same kind
+const INFESTED: u8 = 1<<4;
+/// How many stages the crop grew while fertilized
+const CARE: u8 = 0b11;
 
 impl Crop {
 	pub fn all_interactions(self) -> Vec<Interactable> {
-		let mut interactions = self.typ.interactions();
-		if self.flags & WATERED == 0 {
+		let regrown = self.typ.regrow().map(|typ| Structure::Crop(Self::new(typ).with_genes(self.genes)));
+		let mut interactions: Vec<Interactable> = self.typ.interactions()
+			.into_iter()
+			.map(|interaction| if self.is_infested() {
+					interaction.damaged()
+				} else {
+					interaction.with_bonus(self.genes.yields() + self.care())
+				})
+			.map(|interaction| match regrown {
+				Some(remains) => interaction.leaving(remains),
+				None => interaction,
+			})
+			.collect();
+		if self.is_blighted() {
+			interactions.push(Interactable::transform(InteractionType::Cure, 1, Structure::Crop(Self { flags: self.flags & !BLIGHTED, ..self })));
+		}
+		if self.is_infested() {
+			interactions.push(Interactable::transform(InteractionType::Repel, 1, Structure::Crop(Self { flags: self.flags & !INFESTED, ..self })));
+		}
+		if self.flags & WATERED == 0 && self.typ.grows() {
 			interactions.push(Interactable::transform(InteractionType::Water, 1, Structure::Crop(self.water())));
 		}
-		if self.flags & FERTILIZED == 0 && self.typ.fertilized_grow().is_some() {
+		if self.flags & FERTILIZED == 0 && self.typ.grows() {
 			interactions.push(Interactable::transform(InteractionType::Fertilize, 1, Structure::Crop(self.fertilize())));
 		}
 		interactions
 	}
 	
-	fn water(self) -> Self {
-		Self { typ: self.typ, flags: self.flags | WATERED }
+	pub fn water(self) -> Self {
+		Self { flags: self.flags | WATERED, ..self }
 	}
 	
+	/// Rain waters the crop and drought dries it out again
+	pub fn weather(self, weather: Weather) -> Self {
+		if weather.waters() {
+			self.water()
+		} else if weather.dries() && self.genes.hardiness() < 2 {
+			Self { flags: self.flags & !WATERED, ..self }
+		} else {
+			self
+		}
+	}
+
 	fn fertilize(self) -> Self {
-		Self { typ: self.typ, flags: self.flags | FERTILIZED }
+		Self { flags: self.flags | FERTILIZED, ..self }
+	}
+
+	/// Crops that were fertilized while growing give more
+	fn care(self) -> u8 {
+		self.flags & CARE
+	}
+
+	pub fn is_blighted(self) -> bool {
+		self.flags & BLIGHTED != 0
+	}
+
+	pub fn is_infested(self) -> bool {
+		self.flags & INFESTED != 0
+	}
+
+	pub fn blight(self) -> Self {
+		Self { flags: self.flags | BLIGHTED, ..self }
+	}
+
+	pub fn infest(self) -> Self {
+		Self { flags: self.flags | INFESTED, ..self }
+	}
+
+	/// Whether both crops are of the same kind, so pests and diseases can pass between them
+	pub fn same_kind(self, other: Crop) -> bool {
+		self.typ == other.typ
+	}
+
+	pub fn genes(self) -> Genes {
+		self.genes
+	}
+
+	pub fn with_genes(self, genes: Genes) -> Self {
+		Self { genes, ..self }
+	}
+
+	/// How much faster than normal this crop grows
+	pub fn growth_speed(self) -> i64 {
+		self.genes.speed() as i64
 	}
 	
 	pub fn description(self) -> String {
 		let mut description = self.typ.describe().to_string();
-		if self.flags & WATERED == 0 {
+		if self.genes != Genes::default() {
+			description = format!("{} ({})", description, self.genes);
+		}
+		if self.is_blighted() {
+			description = format!("{}. Blighted", description);
+		}
+		if self.is_infested() {
+			description = format!("{}. Covered in pests", description);
+		}
+		if self.flags & WATERED == 0 && self.typ.grows() {
 			description = format!("{}. Needs water", description);
 		}
-		if self.flags & FERTILIZED == 0 && self.typ.fertilized_grow().is_some() {
+		if self.flags & FERTILIZED == 0 && self.typ.grows() {
 			description = format!("{}. Can be fertilized", description);
 		}
+		if self.typ.regrow().is_some() {
+			description = format!("{}. Grows back after harvest", description);
+		}
 		description
 	}
 	
 	pub fn grow(self) -> Option<(i64, Structure, Option<Structure>)> {
-		if self.flags & WATERED == 0 {
+		if self.flags & WATERED == 0 || self.is_blighted() {
 			return None;
 		}
 		let shoot =
 			if self.flags & FERTILIZED != 0 {
-				self.typ.fertilized_grow().map(|typ| Structure::Crop(Self { typ, flags: 0 }))
+				self.typ.fertilized_grow().map(|typ| Structure::Crop(Self { typ, flags: 0, genes: self.genes }))
 			} else {
 				None
 			};
+		let care = if self.flags & FERTILIZED != 0 {
+				(self.care() + 1).min(CARE)
+			} else {
+				self.care()
+			};
 		if let Some((steps, typ)) = self.typ.next() {
-			let crop = Self { typ, flags: 0 };
+			let crop = Self { typ, flags: care | (self.flags & INFESTED), genes: self.genes };
 			Some((steps, Structure::Crop(crop), shoot))
 		} else if let Some((steps, typ)) = self.typ.grow() {
 			Some((steps, typ, shoot))
@@ -192,11 +448,17 @@ impl Crop {
 		}
 	}
 	
-	pub fn join(self, other: Structure) -> Option<Structure> {
+	/// Crops only progress during their growing seasons, unless they are hardy enough
+	pub fn grows_in(self, season: Season) -> bool {
+		self.genes.hardiness() >= MAX_TRAIT || self.typ.seasons().contains(&season)
+	}
+
+	/// Joined shoots inherit traits from both of them
+	pub fn join(self, other: Structure, rind: u32) -> Option<Structure> {
 		if let Structure::Crop(crop) = other {
 			for (with, product) in self.typ.inosculate() {
 				if with == crop.typ {
-					return Some(Structure::Crop(Self{ typ: product, flags: 0 }))
+					return Some(Structure::Crop(Self{ typ: product, flags: 0, genes: self.genes.cross(crop.genes, rind) }))
 				}
 			}
 		}
@@ -207,8 +469,8 @@ impl Crop {
 		self.typ.sprite()
 	}
 	
-	fn new(typ: CropType) -> Self {
-		Self { typ, flags: 0 }
+	pub fn new(typ: CropType) -> Self {
+		Self { typ, flags: 0, genes: Genes::default() }
 	}
 	
 	pub fn greenseed() -> Self {
@@ -226,3 +488,84 @@ impl Crop {
 	
 }
 
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tile::{Tile, Ground};
+
+	#[test]
+	fn rain_waters_and_drought_dries() {
+		let seed = Crop::greenseed();
+		assert!(seed.grow().is_none());
+		let rained = seed.weather(Weather::Rain);
+		assert!(rained.grow().is_some());
+		assert_eq!(rained.weather(Weather::Clear), rained);
+		assert_eq!(rained.weather(Weather::Drought), seed);
+	}
+
+	#[test]
+	fn nothing_grows_in_winter() {
+		for crop in [Crop::greenseed(), Crop::yellowseed(), Crop::brownseed()] {
+			assert!(!crop.grows_in(Season::Winter));
+			assert!(crop.grows_in(Season::Summer));
+		}
+		assert!(!Crop::greenseed().grows_in(Season::Autumn));
+		assert!(!Crop::yellowseed().grows_in(Season::Spring));
+	}
+
+	#[test]
+	fn crossing_takes_traits_from_parents() {
+		let fast = Genes::default().with(Genes::SPEED, 2).with(Genes::YIELD, 1);
+		let hardy = Genes::default().with(Genes::HARDINESS, 2);
+		let from_parent = |child: &Genes| Genes::TRAITS.into_iter()
+			.all(|shift| child.get(shift) == fast.get(shift) || child.get(shift) == hardy.get(shift));
+		let children: Vec<Genes> = (0..200).map(|rind| fast.inherit(hardy, rind)).collect();
+		assert!(children.iter().all(from_parent));
+		assert!(children.iter().any(|child| child.speed() == 2 && child.hardiness() == 2));
+		assert!(children.iter().any(|child| child.speed() == 0 && child.hardiness() == 0));
+		let mutants = (0..200).filter(|rind| !from_parent(&fast.cross(hardy, *rind))).count();
+		assert!(mutants > 0 && mutants < 100);
+	}
+
+	#[test]
+	fn care_and_tools_raise_yield() {
+		let mut crop = Crop::greenseed();
+		while let Some((_, Structure::Crop(next), _)) = crop.water().fertilize().grow() {
+			crop = next;
+		}
+		assert_eq!(crop.typ, CropType::SeedingDiscPlant);
+		assert_eq!(crop.care(), CARE);
+		let harvested = |crop: Crop, tool: Item| (0..100)
+			.map(|rind| Tile::structure(Ground::Dirt, Structure::Crop(crop)).interact(tool, rind).unwrap().items.len())
+			.sum::<usize>();
+		assert_eq!(harvested(Crop::new(CropType::SeedingDiscPlant), Item::Nothing), 200);
+		assert_eq!(harvested(Crop::new(CropType::SeedingDiscPlant), Item::HardwoodKnife), 400);
+		assert!(harvested(crop, Item::Nothing) > 300);
+		assert!(harvested(crop, Item::HardwoodKnife) > harvested(crop, Item::Nothing));
+		let remains = Tile::structure(Ground::Dirt, Structure::Crop(crop)).interact(Item::Nothing, 0).unwrap().remains;
+		assert_eq!(remains, Some(Structure::Crop(Crop::new(CropType::DiscPlant))));
+	}
+
+	#[test]
+	fn afflictions_hurt_until_treated() {
+		let crop = Crop::new(CropType::SeedingKnifePlant);
+		let harvest = |crop: Crop| Tile::structure(Ground::Dirt, Structure::Crop(crop)).interact(Item::HardwoodKnife, 0).unwrap().items.len();
+		assert_eq!(harvest(crop), 4);
+		assert_eq!(harvest(crop.infest()), 2);
+		let repelled = Tile::structure(Ground::Dirt, Structure::Crop(crop.infest())).interact(Item::Repellent, 0).unwrap().remains;
+		assert_eq!(repelled, Some(Structure::Crop(crop)));
+		let seed = Crop::greenseed().water();
+		assert!(seed.blight().grow().is_none());
+		let cured = Tile::structure(Ground::Dirt, Structure::Crop(seed.blight())).interact(Item::HerbSpray, 0).unwrap().remains;
+		assert_eq!(cured, Some(Structure::Crop(seed)));
+	}
+
+	#[test]
+	fn hardy_crops_stand_drought_and_winter() {
+		let hardy = Crop::greenseed().with_genes(Genes::default().with(Genes::HARDINESS, MAX_TRAIT)).water();
+		assert_eq!(hardy.weather(Weather::Drought), hardy);
+		assert!(hardy.grows_in(Season::Winter));
+		assert_eq!(hardy.water().grow().map(|(_, next, _)| next), Some(Structure::Crop(Crop::new(CropType::GreenSeedling).with_genes(hardy.genes))));
+	}
+}
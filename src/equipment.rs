@@ -0,0 +1,134 @@
+
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use crate::{
+	item::Item,
+	inventory::{Inventory, Stack},
+	worldmessages::EquipmentMessage,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum Slot {
+	Hand,
+	Body,
+	Head,
+	Back,
+}
+
+/// Items that are worn or wielded. Each slot holds a single item
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Equipment {
+	slots: BTreeMap<Slot, Stack>,
+}
+
+impl Equipment {
+
+	pub fn get(&self, slot: Slot) -> Option<Item> {
+		Some(self.slots.get(&slot)?.item())
+	}
+
+	/// Move the item at the index from the inventory into its slot.
+	/// Whatever was in that slot goes back into the inventory.
+	pub fn equip(&mut self, inventory: &mut Inventory, index: usize) -> Result<(), String> {
+		let item = inventory.get_item(index).ok_or("No item to equip")?;
+		let slot = item.slot().ok_or_else(|| format!("A {} can't be equipped", item.name()))?;
+		let mut new_inventory = inventory.clone();
+		let stack = new_inventory.take_one(index).ok_or("No item to equip")?;
+		let mut new_equipment = self.clone();
+		if let Some(old) = new_equipment.slots.insert(slot, stack) {
+			new_inventory.set_capacity_bonus(new_equipment.capacity_bonus());
+			if !new_inventory.put(old) || new_inventory.is_overfull() {
+				return Err("Inventory full".to_string());
+			}
+		}
+		new_inventory.set_capacity_bonus(new_equipment.capacity_bonus());
+		*inventory = new_inventory;
+		*self = new_equipment;
+		Ok(())
+	}
+
+	pub fn unequip(&mut self, inventory: &mut Inventory, slot: Slot) -> Result<(), String> {
+		let mut new_equipment = self.clone();
+		let stack = new_equipment.slots.remove(&slot).ok_or("Nothing to unequip")?;
+		let mut new_inventory = inventory.clone();
+		new_inventory.set_capacity_bonus(new_equipment.capacity_bonus());
+		if !new_inventory.put(stack) || new_inventory.is_overfull() {
+			return Err("Inventory full".to_string());
+		}
+		*inventory = new_inventory;
+		*self = new_equipment;
+		Ok(())
+	}
+
+	/// Use up some durability of the item in the slot.
+	/// When the item breaks it is removed and returned.
+	pub fn wear(&mut self, slot: Slot) -> Option<Item> {
+		let stack = self.slots.get_mut(&slot)?;
+		if !stack.wear_down() {
+			return None;
+		}
+		let item = stack.item();
+		self.slots.remove(&slot);
+		Some(item)
+	}
+
+	/// How much damage from each wound the worn items absorb
+	pub fn armour(&self) -> i32 {
+		self.slots.values().map(|stack| stack.item().armour()).sum()
+	}
+
+	/// How many extra inventory slots the worn items give
+	pub fn capacity_bonus(&self) -> usize {
+		self.slots.values().map(|stack| stack.item().capacity()).sum()
+	}
+
+	pub fn view(&self) -> EquipmentMessage {
+		self.slots.iter()
+			.map(|(slot, stack)| (*slot, (stack.name(), stack.wear())))
+			.collect()
+	}
+
+	pub fn save(&self) -> EquipmentSave {
+		self.slots.clone()
+	}
+
+	pub fn load(saved: EquipmentSave) -> Self {
+		Self { slots: saved }
+	}
+}
+
+pub type EquipmentSave = BTreeMap<Slot, Stack>;
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn equip_swaps_items() {
+		let mut inventory = Inventory::load(vec![(Item::Axe, 1), (Item::Spear, 1)]);
+		let mut equipment = Equipment::default();
+		equipment.equip(&mut inventory, 0).unwrap();
+		assert_eq!(equipment.get(Slot::Hand), Some(Item::Axe));
+		assert_eq!(inventory.get_item(0), Some(Item::Spear));
+		equipment.equip(&mut inventory, 0).unwrap();
+		assert_eq!(equipment.get(Slot::Hand), Some(Item::Spear));
+		assert_eq!(inventory.get_item(0), Some(Item::Axe));
+		assert!(equipment.equip(&mut inventory, 1).is_err());
+	}
+
+	#[test]
+	fn basket_adds_room() {
+		let mut inventory = Inventory::load(vec![(Item::Basket, 1)]);
+		let mut equipment = Equipment::default();
+		equipment.equip(&mut inventory, 0).unwrap();
+		let mut added = 0;
+		while inventory.add(Item::Axe) {
+			added += 1;
+		}
+		assert_eq!(added, Inventory::empty().capacity() + Item::Basket.capacity());
+		assert_eq!(equipment.unequip(&mut inventory, Slot::Back), Err("Inventory full".to_string()));
+		assert_eq!(equipment.get(Slot::Back), Some(Item::Basket));
+	}
+}
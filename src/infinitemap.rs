@@ -2,7 +2,7 @@
 
 use crate::{
 	pos::{Pos, Area},
-	timestamp::Timestamp,
+	timestamp::{Timestamp, Season},
 	tile::{Tile, Ground, Structure},
 	item::Item,
 	basemap::BaseMap,
@@ -137,11 +137,14 @@ impl InfiniteMap {
 		let (bpos, dpos) = self.biome_pos(pos);
 		let biome = self.biome_at(bpos);
 		let rind = WhiteNoise::new(self.seed + 7943).gen(pos);
-		let rtime = WhiteNoise::new(self.seed + 356).gen(pos).wrapping_add(randomtick::tick_num(pos, time) as u32);
+		let tick = randomtick::tick_num(pos, time);
+		let rtime = WhiteNoise::new(self.seed + 356).gen(pos).wrapping_add(tick as u32);
+		// use the season of the last random tick so the tile only changes when it gets ticked
+		let season = Timestamp(tick * randomtick::CHUNK_AREA).season();
 		match biome {
 			Biome::Start => self.gen_start(dpos, rind),
-			Biome::Field => self.gen_field(pos, rind, rtime),
-			Biome::Forest => self.gen_forest(rind, rtime),
+			Biome::Field => self.gen_field(pos, rind, rtime, season),
+			Biome::Forest => self.gen_forest(rind, rtime, season),
 			Biome::Lake => self.gen_lake(pos, rind, rtime),
 			Biome::Rocks => self.gen_rocks(pos, rind, rtime),
 			Biome::Bog => self.gen_bog(pos, rind, rtime),
@@ -151,10 +154,10 @@ impl InfiniteMap {
 	fn gen_start(&self, dpos: Pos, rind: u32) -> Tile {
 		let dspawn = dpos.abs();
 		if dspawn.x == 0 && dspawn.y == 0 {
-			t!(StoneFloor, MarkerAltar)
+			t!(Sanctuary, MarkerAltar)
 		} else if dspawn.x <= 4 && dspawn.y <= 4 && !(dspawn.y == 4 && dspawn.x == 4){
 			if dspawn.x + dspawn.y <= 5 {
-				t!(StoneFloor)
+				t!(Sanctuary)
 			} else {
 				t!(StoneFloor, Wall)
 			}
@@ -180,7 +183,13 @@ impl InfiniteMap {
 			])
 		}
 	}
-	fn gen_field(&self, pos: Pos, rind: u32, rtime: u32) -> Tile {
+	fn gen_field(&self, pos: Pos, rind: u32, rtime: u32, season: Season) -> Tile {
+		let (bare, grass, flowers) = match season {
+			Season::Spring => (30, 10, 5),
+			Season::Summer => (40, 10, 1),
+			Season::Autumn => (50, 6, 0),
+			Season::Winter => (80, 2, 0),
+		};
 		t!(
 			*pick(rind, &[
 				Ground::Grass1,
@@ -189,26 +198,36 @@ impl InfiniteMap {
 			]),
 			if WhiteNoise::new(self.seed + 9429).gen_f(pos) < 0.02 {
 				Structure::Shrub
+			} else if WhiteNoise::new(self.seed + 7121).gen_f(pos) < 0.002 {
+				Structure::NightSpawn(Npc::Worm)
+			} else if WhiteNoise::new(self.seed + 6143).gen_f(pos) < 0.001 {
+				Structure::Spawn(Npc::Sheep)
 			} else {
 				*pick_weighted(randomize_u32(randomize_u32(rtime/20).wrapping_add(5924)), &[
-					(Structure::Air, 40),
-					(Structure::DenseGrassGrn, 4),
-					(Structure::DenseGrassBrn, 3),
-					(Structure::DenseGrassY, 3),
-					(Structure::Flower, 1)
+					(Structure::Air, bare),
+					(Structure::DenseGrassGrn, grass * 4 / 10),
+					(Structure::DenseGrassBrn, grass * 3 / 10),
+					(Structure::DenseGrassY, grass * 3 / 10),
+					(Structure::Flower, flowers)
 				])
 			}
 		)
 	}
-	fn gen_forest(&self, rind: u32, rtime: u32) -> Tile {
+	fn gen_forest(&self, rind: u32, rtime: u32, season: Season) -> Tile {
+		let (grass, leaves) = match season {
+			Season::Spring => (15, 20),
+			Season::Summer => (10, 40),
+			Season::Autumn => (4, 90),
+			Season::Winter => (2, 60),
+		};
 		*pick_weighted(rind, &[
 			(*pick_weighted(rtime/5, &[
 				(*pick_weighted(rind, &[
-					(t!(Grass1), 10),
-					(t!(Grass2), 10),
-					(t!(Grass3), 10),
+					(t!(Grass1), grass),
+					(t!(Grass2), grass),
+					(t!(Grass3), grass),
 					(t!(Moss), 40),
-					(t!(DeadLeaves), 40),
+					(t!(DeadLeaves), leaves),
 					(t!(Dirt), 30),
 					(t!(Dirt, Structure::Item(Item::Stick)), 1)
 				]), 100),
@@ -219,6 +238,7 @@ impl InfiniteMap {
 				(t!(Dirt), 1)
 			]), 100),
 			(t!(Dirt, Structure::Spawn(Npc::Worm)), 1),
+			(t!(Grass1, Structure::Spawn(Npc::Hen)), 1),
 		])
 	}
 	fn gen_lake(&self, pos: Pos, rind: u32, rtime: u32) -> Tile {
@@ -328,6 +348,10 @@ impl BaseMap for InfiniteMap {
 	fn player_spawn(&self) -> Pos {
 		self.start_pos()
 	}
+
+	fn seed(&self) -> u32 {
+		self.seed
+	}
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
@@ -363,9 +387,9 @@ mod tests {
 	}
 
 	#[test]
-	fn start_pos_has_stone_floor() {
+	fn start_pos_is_sanctuary() {
 		let map = InfiniteMap::new(9876);
-		assert_eq!(map.cell(map.start_pos(), Timestamp(1)), t!(StoneFloor));
+		assert_eq!(map.cell(map.start_pos(), Timestamp(1)), t!(Sanctuary));
 	}
 
 	#[test]
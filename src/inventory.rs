@@ -1,93 +1,369 @@
 
 use std::collections::HashMap;
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
 use crate::{
 	item::Item,
+	crop::Genes,
 	worldmessages::InventoryMessage,
+	random,
 };
 
+const MAX_NAME_LENGTH: usize = 32;
+const DEFAULT_CAPACITY: usize = 32;
+
+/// State that belongs to a specific instance of an item
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemData {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub durability: Option<u32>,
+	#[serde(default, skip_serializing_if = "is_zero")]
+	pub quality: u8,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub genes: Option<Genes>,
+}
+fn is_zero(n: &u8) -> bool { *n == 0 }
+
+impl ItemData {
+	pub fn new(item: Item) -> Self {
+		Self {
+			durability: item.durability(),
+			..Default::default()
+		}
+	}
+
+	pub fn crafted(item: Item, rind: u32) -> Self {
+		if item.durability().is_none() {
+			return Self::default();
+		}
+		let quality = *random::pick_weighted(rind, &[(0, 12), (1, 5), (2, 1)]);
+		Self {
+			durability: max_durability(item, quality),
+			quality,
+			..Default::default()
+		}
+	}
+
+	/// A seed that will grow into a crop with these genes
+	pub fn seed(item: Item, genes: Genes) -> Self {
+		Self {
+			genes: Some(genes).filter(|genes| *genes != Genes::default()),
+			..Self::new(item)
+		}
+	}
+}
+
+fn max_durability(item: Item, quality: u8) -> Option<u32> {
+	item.durability().map(|durability| durability * (4 + quality as u32) / 4)
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack {
+	item: Item,
+	count: usize,
+	data: ItemData,
+}
+
+impl Stack {
+	pub fn item(&self) -> Item {
+		self.item
+	}
+
+	pub fn name(&self) -> String {
+		if let Some(name) = &self.data.name {
+			return name.clone();
+		}
+		let name = match self.data.quality {
+			0 => self.item.name().to_string(),
+			1 => format!("fine {}", self.item.name()),
+			_ => format!("excellent {}", self.item.name()),
+		};
+		match self.data.genes {
+			Some(genes) => format!("{} ({})", name, genes),
+			None => name,
+		}
+	}
+
+	pub fn wear(&self) -> Option<(u32, u32)> {
+		Some((self.data.durability?, max_durability(self.item, self.data.quality)?))
+	}
+
+	/// Use up some durability. Returns whether one of the items broke
+	pub fn wear_down(&mut self) -> bool {
+		let Some(durability) = self.data.durability.as_mut() else {
+			return false;
+		};
+		*durability = durability.saturating_sub(1);
+		if *durability > 0 {
+			return false;
+		}
+		self.count -= 1;
+		self.data.durability = max_durability(self.item, self.data.quality);
+		true
+	}
+
+	fn describe(&self) -> String {
+		let mut description = self.item.description().to_string();
+		if let Some((durability, max)) = self.wear() {
+			description = format!("{}. Durability: {}/{}", description, durability, max);
+		}
+		description
+	}
+}
+
+impl From<(Item, usize)> for Stack {
+	fn from((item, count): (Item, usize)) -> Self {
+		Self { item, count, data: ItemData::default() }
+	}
+}
+
+impl Serialize for Stack {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		if self.data == ItemData::default() {
+			(self.item, self.count).serialize(serializer)
+		} else {
+			(self.item, self.count, &self.data).serialize(serializer)
+		}
+	}
+}
+impl<'de> Deserialize<'de> for Stack {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum SavedStack {
+			Plain(Item, usize),
+			WithData(Item, usize, ItemData),
+		}
+		Ok(match SavedStack::deserialize(deserializer)? {
+			SavedStack::Plain(item, count) => Self::from((item, count)),
+			SavedStack::WithData(item, count, data) => Self { item, count, data },
+		})
+	}
+}
+
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Inventory {
-	items: Vec<(Item, usize)>
+	items: Vec<Stack>,
+	capacity: usize,
+	capacity_bonus: usize,
+	selected: Option<usize>,
 }
 
 impl Inventory {
-	
-	pub fn add(&mut self, item: Item) {
-		for entry in self.items.iter_mut() {
-			if entry.0 == item {
-				entry.1 += 1;
-				return;
+
+	/// Add an item if there is room for it
+	pub fn add(&mut self, item: Item) -> bool {
+		self.add_instance(item, ItemData::new(item))
+	}
+
+	pub fn add_instance(&mut self, item: Item, data: ItemData) -> bool {
+		if item.durability().is_none() {
+			for entry in self.items.iter_mut() {
+				if entry.item == item && entry.data == data && entry.count < item.stack_size() {
+					entry.count += 1;
+					return true;
+				}
 			}
 		}
-		self.items.push((item, 1));
+		if self.items.len() >= self.capacity() {
+			return false;
+		}
+		self.items.push(Stack { item, count: 1, data });
+		true
+	}
+
+	/// Put back a single item that was taken out with take_one
+	pub fn put(&mut self, stack: Stack) -> bool {
+		self.add_instance(stack.item, stack.data)
+	}
+
+	/// Remove a single item from the stack at the index
+	pub fn take_one(&mut self, index: usize) -> Option<Stack> {
+		let stack = self.items.get_mut(index)?;
+		stack.count -= 1;
+		let taken = Stack { count: 1, ..stack.clone() };
+		if stack.count == 0 {
+			self.items.remove(index);
+		}
+		Some(taken)
+	}
+
+	pub fn capacity(&self) -> usize {
+		self.capacity + self.capacity_bonus
 	}
-	
+
+	pub fn set_capacity_bonus(&mut self, bonus: usize) {
+		self.capacity_bonus = bonus;
+	}
+
+	pub fn is_overfull(&self) -> bool {
+		self.items.len() > self.capacity()
+	}
+
+	/// Whether all items would fit after paying the cost
+	pub fn fits(&self, cost: &HashMap<Item, usize>, items: &[Item]) -> bool {
+		let mut inventory = self.clone();
+		inventory.pay(cost.clone()) && items.iter().all(|item| inventory.add(*item))
+	}
+
 	pub fn view(&self) -> InventoryMessage {
 		let view = self.items.iter()
-			.map(|(item, count)| (item.name().to_string(), if item.quantified() { Some(*count) } else {None}))
+			.map(|stack| (
+				stack.name(),
+				if stack.item.quantified() { Some(stack.count) } else {None},
+				stack.wear()
+			))
 			.collect();
-		(view, None)
+		(view, self.selected())
 	}
-	
+
 	pub fn save(&self) -> InventorySave {
 		self.items.clone()
 	}
-	
-	pub fn load(saved: InventorySave) -> Self {
+
+	pub fn load(saved: impl IntoIterator<Item=impl Into<Stack>>) -> Self {
+		let mut items = Vec::new();
+		for stack in saved.into_iter().map(Into::into) {
+			// tools from before durability existed get split into fresh instances
+			if stack.data.durability.is_none() && stack.item.durability().is_some() {
+				for _ in 0..stack.count {
+					items.push(Stack {
+						item: stack.item,
+						count: 1,
+						data: ItemData { durability: max_durability(stack.item, stack.data.quality), ..stack.data.clone() }
+					});
+				}
+			} else {
+				items.push(stack);
+			}
+		}
 		Self {
-			items: saved,
+			items,
+			capacity: DEFAULT_CAPACITY,
+			capacity_bonus: 0,
+			selected: None,
 		}
 	}
 
 	pub fn empty() -> Self {
-		Self { items: Vec::new() }
+		Self::load(Vec::<Stack>::new())
 	}
-	
+
+	pub fn selected(&self) -> Option<usize> {
+		self.selected.filter(|index| *index < self.count())
+	}
+
+	pub fn select(&mut self, index: Option<usize>) {
+		self.selected = index;
+	}
+
 	fn count(&self) -> usize {
 		self.items.len()
 	}
-	
+
 	pub fn move_item(&mut self, from: usize, target: usize) {
 		if from > self.count() || target > self.count() || from == target{
 			return;
 		}
 		let item = self.items.remove(from);
 		self.items.insert(target, item);
+		// keep the selection on the same item
+		self.selected = self.selected.map(|index| {
+			if index == from {
+				target
+			} else if from < index && index <= target {
+				index - 1
+			} else if target <= index && index < from {
+				index + 1
+			} else {
+				index
+			}
+		});
+	}
+
+	pub fn name_item(&mut self, index: usize, name: Option<String>) {
+		if let Some(stack) = self.items.get_mut(index) {
+			stack.data.name = name
+				.map(|name| name.chars().filter(|c| !c.is_control()).take(MAX_NAME_LENGTH).collect::<String>())
+				.filter(|name| !name.is_empty());
+		}
 	}
 
 	pub fn get_item(&self, index: usize) -> Option<Item> {
-		Some(self.items.get(index)?.0)
-	}
-	
-	pub fn pay(&mut self, mut cost: HashMap<Item, usize>) -> bool {
-		if cost.is_empty() {
-			return true;
-		}
-		if let Some(items) = self.items.iter()
-				.map(|(item, n)| {
-					let amount = cost.remove(item).unwrap_or(0);
-					if amount > *n {
-						None
-					} else {
-						Some((*item, *n - amount))
-					}
-				})
-				.collect::<Option<Vec<(Item, usize)>>>() {
-			if !cost.is_empty() {
-				false
-			} else {
-				self.items = items;
-				self.items.retain(|(_, n)| *n > 0);
-				true
+		Some(self.items.get(index)?.item)
+	}
+
+	pub fn get_data(&self, index: usize) -> Option<&ItemData> {
+		Some(&self.items.get(index)?.data)
+	}
+
+	pub fn describe_item(&self, index: usize) -> Option<String> {
+		Some(self.items.get(index)?.describe())
+	}
+
+	/// Use up some durability of the item at the index.
+	/// When the item breaks it is removed and returned.
+	pub fn wear(&mut self, index: usize) -> Option<Item> {
+		let stack = self.items.get_mut(index)?;
+		if !stack.wear_down() {
+			return None;
+		}
+		let item = stack.item;
+		if stack.count == 0 {
+			self.items.remove(index);
+		}
+		Some(item)
+	}
+
+	pub fn can_afford(&self, cost: &HashMap<Item, usize>) -> bool {
+		self.affordable_count(cost) > 0
+	}
+
+	/// How many times the cost can be paid with the items in this inventory
+	pub fn affordable_count(&self, cost: &HashMap<Item, usize>) -> usize {
+		cost.iter()
+			.filter(|(_, amount)| **amount > 0)
+			.map(|(item, amount)| self.items.iter().filter(|stack| stack.item == *item).map(|stack| stack.count).sum::<usize>() / amount)
+			.min()
+			.unwrap_or(usize::MAX)
+	}
+
+	/// Pay the cost, taking from the stack at the index first
+	pub fn pay_preferring(&mut self, mut cost: HashMap<Item, usize>, index: usize) -> bool {
+		if !self.can_afford(&cost) {
+			return false;
+		}
+		if let Some(item) = self.get_item(index) {
+			if let Some(amount) = cost.get_mut(&item).filter(|amount| **amount > 0) {
+				*amount -= 1;
+				self.take_one(index);
 			}
-		} else {
-			false
 		}
+		self.pay(cost)
+	}
+
+	pub fn pay(&mut self, cost: HashMap<Item, usize>) -> bool {
+		if !self.can_afford(&cost) {
+			return false;
+		}
+		for (item, mut amount) in cost {
+			for stack in self.items.iter_mut().filter(|stack| stack.item == item) {
+				let paid = amount.min(stack.count);
+				stack.count -= paid;
+				amount -= paid;
+			}
+		}
+		self.items.retain(|stack| stack.count > 0);
+		true
 	}
 }
 
-pub type InventorySave = Vec<(Item, usize)>;
+pub type InventorySave = Vec<Stack>;
 
 
 #[cfg(test)]
@@ -107,5 +383,51 @@ mod tests {
 		assert_eq!(inv, expected);
 
 	}
+	#[test]
+	fn selection_follows_moved_items() {
+		let mut inv = Inventory::load(vec![(Item::Stone, 1), (Item::Stick, 1), (Item::Ash, 1), (Item::Log, 1)]);
+		inv.select(Some(2));
+		inv.move_item(0, 3);
+		assert_eq!(inv.selected(), Some(1));
+		inv.move_item(3, 0);
+		assert_eq!(inv.selected(), Some(2));
+		inv.move_item(2, 1);
+		assert_eq!(inv.get_item(inv.selected().unwrap()), Some(Item::Ash));
+	}
+	#[test]
+	fn counts_affordable() {
+		let inv = Inventory::load(vec![(Item::Stone, 3), (Item::Flower, 25)]);
+		assert_eq!(inv.affordable_count(&crate::hashmap![Item::Stone => 1, Item::Flower => 10]), 2);
+		assert_eq!(inv.affordable_count(&crate::hashmap![Item::Stone => 1, Item::Log => 1]), 0);
+	}
+	#[test]
+	fn tool_breaks_when_worn_out() {
+		let mut inv = Inventory::empty();
+		inv.add(Item::Axe);
+		let durability = Item::Axe.durability().unwrap();
+		for _ in 1..durability {
+			assert_eq!(inv.wear(0), None);
+		}
+		assert_eq!(inv.wear(0), Some(Item::Axe));
+		assert_eq!(inv.get_item(0), None);
+	}
+	#[test]
+	fn stacks_are_limited() {
+		let mut inv = Inventory { capacity: 2, ..Inventory::empty() };
+		for _ in 0..Item::Log.stack_size() * 2 {
+			assert!(inv.add(Item::Log));
+		}
+		assert!(!inv.add(Item::Log));
+		assert!(!inv.add(Item::Stone));
+		assert!(inv.fits(&crate::hashmap![Item::Log => Item::Log.stack_size()], &[Item::Stone]));
+	}
+	#[test]
+	fn old_saves_get_durability() {
+		let inv: Inventory = Inventory::load(serde_json::from_str::<InventorySave>(r#"[["axe", 2], ["log", 3, {"name": "firewood"}]]"#).unwrap());
+		assert_eq!(inv.view().0, vec![
+			("axe".to_string(), Some(1), Some((100, 100))),
+			("axe".to_string(), Some(1), Some((100, 100))),
+			("firewood".to_string(), Some(3), None),
+		]);
+	}
 }
-
@@ -9,167 +9,442 @@ use crate::{
 	hashmap,
 	crop::Crop,
 	sprite::Sprite,
+	content,
+	timestamp::Duration,
+	equipment::Slot,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Assoc)]
 #[serde(rename_all="snake_case")]
-#[func(pub fn actions(self) -> Vec<Action> {Vec::new()})]
-#[func(pub fn description(&self) -> &str)]
-#[func(pub fn name(&self) -> &str)]
+#[func(fn builtin_actions(self) -> Vec<Action> {Vec::new()})]
+#[func(fn builtin_description(&self) -> &str)]
+#[func(fn builtin_name(&self) -> &str)]
 #[func(pub fn quantified(self) -> bool {true})]
 #[func(pub fn sprite(self) -> Option<Sprite>)]
+#[func(fn builtin_durability(self) -> Option<u32>)]
+#[func(fn builtin_stack_size(self) -> usize {64})]
+#[func(fn builtin_weapon(self) -> Option<Weapon>)]
+#[func(fn builtin_armour(self) -> i32 {0})]
+#[func(fn builtin_slot(self) -> Option<Slot>)]
+#[func(fn builtin_capacity(self) -> usize {0})]
+#[func(fn builtin_food(self) -> i32 {0})]
 pub enum Item {
-	#[assoc(name="<nothing>")]
-	#[assoc(description="Your empty hands")]
-	#[assoc(actions=vec![Action::interact(Take, 1, false)])]
+	#[assoc(builtin_name="<nothing>")]
+	#[assoc(builtin_description="Your empty hands")]
+	#[assoc(builtin_actions=vec![Action::interact(Take, 1, false)])]
 	Nothing,
 
-	#[assoc(name="reed")]
-	#[assoc(description="Some cut reeds")]
+	#[assoc(builtin_name="reed")]
+	#[assoc(builtin_description="Some cut reeds")]
+	#[assoc(builtin_actions=vec![
+		Action::Craft(CraftType::GardeningTable, Item::ReedHat, hashmap![Item::Reed => 4]),
+		Action::Craft(CraftType::GardeningTable, Item::Basket, hashmap![Item::Reed => 7, Item::Stick => 2]),
+		Action::interact(Compost, 1, true)
+	])]
 	Reed,
 	
-	#[assoc(name="flower")]
-	#[assoc(description="A pretty flower")]
-	#[assoc(actions=vec![Action::Craft(CraftType::Marker, Item::MarkerStone, hashmap![Item::Stone => 1, Item::Flower => 9])])]
+	#[assoc(builtin_name="flower")]
+	#[assoc(builtin_description="A pretty flower")]
+	#[assoc(builtin_actions=vec![
+		Action::Craft(CraftType::Marker, Item::MarkerStone, hashmap![Item::Stone => 1, Item::Flower => 9]),
+		Action::Craft(CraftType::GardeningTable, Item::HerbSpray, hashmap![Item::Flower => 2, Item::FilledPitcher => 1])
+	])]
 	Flower,
 	
-	#[assoc(name="pebble")]
+	#[assoc(builtin_name="pebble")]
 	#[assoc(sprite = Sprite::Pebble)]
-	#[assoc(description="Pebble. A small stone")]
+	#[assoc(builtin_description="Pebble. A small stone")]
 	Pebble,
 	
-	#[assoc(name="stone")]
-	#[assoc(description="A mid-size stone. Stones can be broken by smashing two together")]
-	#[assoc(actions=vec![Action::interact(Smash, 1, true)])]
+	#[assoc(builtin_name="stone")]
+	#[assoc(builtin_description="A mid-size stone. Stones can be broken by smashing two together")]
+	#[assoc(builtin_actions=vec![Action::interact(Smash, 1, true)])]
 	Stone,
 	
-	#[assoc(name="sharp stone")]
-	#[assoc(description="A small stone with a sharp edge. It can be used to cut things, though it is very crude and may not always work")]
-	#[assoc(actions=vec![Action::interact(Cut, 1, false)])]
+	#[assoc(builtin_name="sharp stone")]
+	#[assoc(builtin_description="A small stone with a sharp edge. It can be used to cut things, though it is very crude and may not always work")]
+	#[assoc(builtin_actions=vec![Action::interact(Cut, 1, false)])]
+	#[assoc(builtin_durability=20)]
+	#[assoc(builtin_weapon = Weapon {damage: 6, reach: 1, cooldown: Duration(10)})]
+	#[assoc(builtin_slot = Slot::Hand)]
 	SharpStone,
 	
-	#[assoc(name="pitcher")]
-	#[assoc(description="A pitcher from the pitcher plant. It can function as a bucket")]
-	#[assoc(actions=vec![Action::Craft(CraftType::Water, Item::FilledPitcher, HashMap::new())])]
+	#[assoc(builtin_name="pitcher")]
+	#[assoc(builtin_description="A pitcher from the pitcher plant. It can function as a bucket")]
+	#[assoc(builtin_actions=vec![
+		Action::Craft(CraftType::Water, Item::FilledPitcher, HashMap::new()),
+		Action::Craft(CraftType::GardeningTable, Item::Sprinkler, hashmap![Item::Plank => 2])
+	])]
 	Pitcher,
 	
-	#[assoc(name="water pitcher")]
-	#[assoc(description="A pitcher from the pitcher plant, filled with water")]
-	#[assoc(actions=vec![Action::interact_change(Water, 1, Item::Pitcher)])]
+	#[assoc(builtin_name="water pitcher")]
+	#[assoc(builtin_description="A pitcher from the pitcher plant, filled with water")]
+	#[assoc(builtin_actions=vec![Action::interact_change(Water, 1, Item::Pitcher)])]
 	FilledPitcher,
 	
-	#[assoc(name="hoe")]
-	#[assoc(description="A simple hoe that can be used to clear the ground of small vegetation")]
-	#[assoc(actions=vec![Action::Clear])]
+	#[assoc(builtin_name="hoe")]
+	#[assoc(builtin_description="A simple hoe that can be used to clear the ground of small vegetation and to dig irrigation channels in dirt")]
+	#[assoc(builtin_actions=vec![Action::Clear, Action::Dig])]
+	#[assoc(builtin_durability=60)]
+	#[assoc(builtin_slot = Slot::Hand)]
 	Hoe,
 	
-	#[assoc(name="green seed")]
-	#[assoc(description="Unknown green seed")]
-	#[assoc(actions=vec![Action::Build(Structure::Crop(Crop::greenseed()), HashMap::new())])]
+	#[assoc(builtin_name="green seed")]
+	#[assoc(builtin_description="Unknown green seed")]
+	#[assoc(builtin_actions=vec![Action::Build(Structure::Crop(Crop::greenseed()), HashMap::new())])]
 	GreenSeed,
 	
-	#[assoc(name="yellow seed")]
-	#[assoc(actions=vec![Action::Build(Structure::Crop(Crop::yellowseed()), HashMap::new())])]
-	#[assoc(description="Unknown yellow seed")]
+	#[assoc(builtin_name="yellow seed")]
+	#[assoc(builtin_actions=vec![Action::Build(Structure::Crop(Crop::yellowseed()), HashMap::new())])]
+	#[assoc(builtin_description="Unknown yellow seed")]
 	YellowSeed,
 	
-	#[assoc(name="brown seed")]
-	#[assoc(actions=vec![Action::Build(Structure::Crop(Crop::brownseed()), HashMap::new())])]
-	#[assoc(description="Unknown brown seed")]
+	#[assoc(builtin_name="brown seed")]
+	#[assoc(builtin_actions=vec![Action::Build(Structure::Crop(Crop::brownseed()), HashMap::new())])]
+	#[assoc(builtin_description="Unknown brown seed")]
 	BrownSeed,
 	
-	#[assoc(name="stick")]
-	#[assoc(description="Wooden stick")]
+	#[assoc(builtin_name="stick")]
+	#[assoc(builtin_description="Wooden stick")]
 	#[assoc(sprite = Sprite::Stick)]
-	#[assoc(actions=vec![
+	#[assoc(builtin_actions=vec![
 		Action::Craft(CraftType::GardeningTable, Item::Hoe, hashmap![Item::Reed => 1, Item::SharpStone => 1]),
 		Action::interact(Fuel, 1, true)
 	])]
 	Stick,
 	
-	#[assoc(name="discleaf")]
-	#[assoc(description="Disk leaf")]
-	#[assoc(actions=vec![
-		Action::interact(Fuel, 1, true)
+	#[assoc(builtin_name="discleaf")]
+	#[assoc(builtin_description="Disk leaf. Edible, but better when cooked")]
+	#[assoc(builtin_actions=vec![
+		Action::interact(Fuel, 1, true),
+		Action::Craft(CraftType::Cooking, Item::BakedLeaves, hashmap![Item::DiscLeaf => 2]),
+		Action::interact(Compost, 1, true)
 	])]
+	#[assoc(builtin_food=3)]
 	DiscLeaf,
 	
-	#[assoc(name="knifeleaf")]
-	#[assoc(description="Knife leaf")]
-	#[assoc(actions=vec![
-		Action::interact(Cut, 2, true)
+	#[assoc(builtin_name="knifeleaf")]
+	#[assoc(builtin_description="Knife leaf")]
+	#[assoc(builtin_actions=vec![
+		Action::interact(Cut, 2, true),
+		Action::interact(Compost, 1, true),
+		Action::Craft(CraftType::GardeningTable, Item::Repellent, hashmap![Item::KnifeLeaf => 1])
 	])]
 	KnifeLeaf,
 	
-	#[assoc(name="hardwood stick")]
-	#[assoc(description="A strong stick")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="hardwood stick")]
+	#[assoc(builtin_description="A strong stick")]
+	#[assoc(builtin_actions=vec![
 		Action::interact(Fuel, 2, true)
 	])]
 	HardwoodStick,
 	
-	#[assoc(name="wood knife")]
-	#[assoc(description="A surprisingly effective wooden knife")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="wood knife")]
+	#[assoc(builtin_description="A surprisingly effective wooden knife")]
+	#[assoc(builtin_actions=vec![
 		Action::Craft(CraftType::GardeningTable, Item::Axe, hashmap![Item::Reed => 1, Item::HardwoodStick=> 1]),
 		Action::interact(Cut, 2, false)
 	])]
+	#[assoc(builtin_durability=80)]
+	#[assoc(builtin_weapon = Weapon {damage: 7, reach: 1, cooldown: Duration(8)})]
+	#[assoc(builtin_slot = Slot::Hand)]
 	HardwoodKnife,
 	
-	#[assoc(name="wood table")]
-	#[assoc(description="A wooden table")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="wood table")]
+	#[assoc(builtin_description="A wooden table")]
+	#[assoc(builtin_actions=vec![
 		Action::Build(Structure::HardwoodTable, HashMap::new())
 	])]
 	HardwoodTable,
 	
-	#[assoc(name="tinder")]
-	#[assoc(description="Tinder from the tinder fungus. Can be placed with some pebbles on a clear space to create a fireplace")]
-	#[assoc(actions=vec![Action::Build(Structure::Fireplace, hashmap![Item::Pebble => 10])])]
+	#[assoc(builtin_name="tinder")]
+	#[assoc(builtin_description="Tinder from the tinder fungus. Can be placed with some pebbles on a clear space to create a fireplace, or used to set something on fire")]
+	#[assoc(builtin_actions=vec![
+		Action::Build(Structure::Fireplace, hashmap![Item::Pebble => 10]),
+		Action::interact(Ignite, 1, true)
+	])]
 	Tinder,
 	
-	#[assoc(name="marker stone")]
-	#[assoc(description="A marker stone that can be placed to create a land claim")]
-	#[assoc(actions=vec![Action::BuildClaim(Structure::MarkStone)])]
+	#[assoc(builtin_name="marker stone")]
+	#[assoc(builtin_description="A marker stone that can be placed to create a land claim")]
+	#[assoc(builtin_actions=vec![Action::BuildClaim(Structure::MarkStone)])]
 	MarkerStone,
 	
-	#[assoc(name="ash")]
-	#[assoc(description="Wood ash. Can be used as fertilizer")]
-	#[assoc(actions=vec![Action::interact(Fertilize, 1, true)])]
+	#[assoc(builtin_name="ash")]
+	#[assoc(builtin_description="Wood ash. Can be used as fertilizer")]
+	#[assoc(builtin_actions=vec![Action::interact(Fertilize, 1, true)])]
 	Ash,
 	
-	#[assoc(name="axe")]
-	#[assoc(description="A wooden axe")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="axe")]
+	#[assoc(builtin_description="A wooden axe")]
+	#[assoc(builtin_actions=vec![
 		Action::interact(Chop, 2, false)
 	])]
+	#[assoc(builtin_durability=100)]
+	#[assoc(builtin_weapon = Weapon {damage: 9, reach: 1, cooldown: Duration(16)})]
+	#[assoc(builtin_slot = Slot::Hand)]
 	Axe,
 	
-	#[assoc(name="log")]
-	#[assoc(description="Wooden log")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="log")]
+	#[assoc(builtin_description="Wooden log")]
+	#[assoc(builtin_actions=vec![
 		Action::Craft(CraftType::SawTable, Item::Plank, HashMap::new()),
 		Action::interact(Fuel, 2, true)
 	])]
 	Log,
 	
 	
-	#[assoc(name="sawblade")]
-	#[assoc(description="Wooden round saw blade")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="sawblade")]
+	#[assoc(builtin_description="Wooden round saw blade")]
+	#[assoc(builtin_actions=vec![
 		Action::interact(BuildSaw, 1, true)
 	])]
 	SawBlade,
 	
-	#[assoc(name="plank")]
-	#[assoc(description="Wooden plank")]
-	#[assoc(actions=vec![
+	#[assoc(builtin_name="plank")]
+	#[assoc(builtin_description="Wooden plank")]
+	#[assoc(builtin_actions=vec![
 		Action::Build(Structure::PlankWall, HashMap::new()),
-		Action::interact(Fuel, 2, true)
+		Action::interact(Fuel, 2, true),
+		Action::Craft(CraftType::GardeningTable, Item::CompostBin, hashmap![Item::Plank => 3])
 	])]
 	Plank,
+	
+	#[assoc(builtin_name="slime")]
+	#[assoc(builtin_description="Sticky worm slime. It works well as a glue")]
+	#[assoc(sprite = Sprite::Slime)]
+	#[assoc(builtin_actions=vec![
+		Action::Craft(CraftType::GardeningTable, Item::Spear, hashmap![Item::Stick => 1, Item::SharpStone => 1])
+	])]
+	Slime,
+	
+	#[assoc(builtin_name="worm hide")]
+	#[assoc(builtin_description="The tough skin of a worm")]
+	#[assoc(sprite = Sprite::Hide)]
+	#[assoc(builtin_actions=vec![
+		Action::Craft(CraftType::GardeningTable, Item::HideVest, hashmap![Item::WormHide => 2, Item::Reed => 2])
+	])]
+	WormHide,
+	
+	#[assoc(builtin_name="frog leg")]
+	#[assoc(builtin_description="A raw frog leg. It can be roasted on a fire")]
+	#[assoc(builtin_actions=vec![
+		Action::Craft(CraftType::Cooking, Item::RoastFrogLeg, HashMap::new())
+	])]
+	#[assoc(builtin_food=5)]
+	FrogLeg,
+	
+	#[assoc(builtin_name="spear")]
+	#[assoc(builtin_description="A sharp stone glued to a stick. Good for hunting")]
+	#[assoc(builtin_actions=vec![
+		Action::interact(Cut, 1, false)
+	])]
+	#[assoc(builtin_durability=40)]
+	#[assoc(builtin_weapon = Weapon {damage: 7, reach: 2, cooldown: Duration(12)})]
+	#[assoc(builtin_slot = Slot::Hand)]
+	Spear,
+	
+	#[assoc(builtin_name="hide vest")]
+	#[assoc(builtin_description="A vest made of worm hide. It protects against some damage")]
+	#[assoc(builtin_armour=2)]
+	#[assoc(builtin_slot = Slot::Body)]
+	HideVest,
+	
+	#[assoc(builtin_name="reed hat")]
+	#[assoc(builtin_description="A woven reed hat. It protects against a little damage")]
+	#[assoc(builtin_armour=1)]
+	#[assoc(builtin_slot = Slot::Head)]
+	ReedHat,
+	
+	#[assoc(builtin_name="basket")]
+	#[assoc(builtin_description="A reed basket to carry on your back. It gives room for more items")]
+	#[assoc(builtin_capacity=8)]
+	#[assoc(builtin_slot = Slot::Back)]
+	Basket,
+	
+	#[assoc(builtin_name="berries")]
+	#[assoc(builtin_description="A handful of wild berries")]
+	#[assoc(builtin_food=8)]
+	Berries,
+	
+	#[assoc(builtin_name="roast frog leg")]
+	#[assoc(builtin_description="A frog leg roasted on a fire. Very filling")]
+	#[assoc(builtin_food=30)]
+	RoastFrogLeg,
+	
+	#[assoc(builtin_name="baked leaves")]
+	#[assoc(builtin_description="Disk leaves baked until crispy")]
+	#[assoc(builtin_food=20)]
+	BakedLeaves,
+	
+	#[assoc(builtin_name="sprinkler")]
+	#[assoc(builtin_description="A pitcher on a wooden stand. Place it next to water to water the crops around it")]
+	#[assoc(builtin_actions=vec![Action::Build(Structure::Sprinkler, HashMap::new())])]
+	Sprinkler,
+	
+	#[assoc(builtin_name="sapling")]
+	#[assoc(builtin_description="A young tree. Plant it on your land and it will grow into a tree")]
+	#[assoc(builtin_actions=vec![Action::Build(Structure::Sapling, HashMap::new())])]
+	Sapling,
+	
+	#[assoc(builtin_name="compost bin")]
+	#[assoc(builtin_description="A wooden bin. Plant waste put in it slowly turns into compost")]
+	#[assoc(builtin_actions=vec![Action::Build(Structure::CompostBin, HashMap::new())])]
+	CompostBin,
+	
+	#[assoc(builtin_name="compost")]
+	#[assoc(builtin_description="Rich compost. Can be used as fertilizer or spread on tired soil to make it fertile again")]
+	#[assoc(builtin_actions=vec![Action::interact(Fertilize, 1, true), Action::Enrich])]
+	Compost,
+	
+	#[assoc(builtin_name="herb spray")]
+	#[assoc(builtin_description="A pitcher of flower brew. Cures blighted crops")]
+	#[assoc(builtin_actions=vec![Action::interact_change(Cure, 1, Item::Pitcher)])]
+	HerbSpray,
+	
+	#[assoc(builtin_name="repellent")]
+	#[assoc(builtin_description="Crushed knife leaves. The smell drives pests away from crops")]
+	#[assoc(builtin_actions=vec![Action::interact(Repel, 1, true)])]
+	Repellent,
+	
+	#[assoc(builtin_name="wool")]
+	#[assoc(builtin_description="Soft wool from a tame sheep")]
+	Wool,
+	
+	#[assoc(builtin_name="egg")]
+	#[assoc(builtin_description="An egg laid by a tame hen")]
+	#[assoc(builtin_food=10)]
+	Egg,
 }
 
+impl Item {
+	pub const ALL: [Item; 43] = [
+		Item::Nothing,
+		Item::Reed,
+		Item::Flower,
+		Item::Pebble,
+		Item::Stone,
+		Item::SharpStone,
+		Item::Pitcher,
+		Item::FilledPitcher,
+		Item::Hoe,
+		Item::GreenSeed,
+		Item::YellowSeed,
+		Item::BrownSeed,
+		Item::Stick,
+		Item::DiscLeaf,
+		Item::KnifeLeaf,
+		Item::HardwoodStick,
+		Item::HardwoodKnife,
+		Item::HardwoodTable,
+		Item::Tinder,
+		Item::MarkerStone,
+		Item::Ash,
+		Item::Axe,
+		Item::Log,
+		Item::SawBlade,
+		Item::Plank,
+		Item::Slime,
+		Item::WormHide,
+		Item::FrogLeg,
+		Item::Spear,
+		Item::HideVest,
+		Item::ReedHat,
+		Item::Basket,
+		Item::Berries,
+		Item::RoastFrogLeg,
+		Item::BakedLeaves,
+		Item::Sprinkler,
+		Item::Sapling,
+		Item::CompostBin,
+		Item::Compost,
+		Item::HerbSpray,
+		Item::Repellent,
+		Item::Wool,
+		Item::Egg,
+	];
+
+	pub fn actions(self) -> Vec<Action> {
+		let mut actions = content::item(self)
+			.and_then(|def| def.actions.clone())
+			.unwrap_or_else(|| self.builtin_actions());
+		actions.extend(content::recipes(self));
+		actions
+	}
+
+	/// Whether this item can be planted as a crop
+	pub fn is_seed(self) -> bool {
+		self.actions().iter().any(|action| matches!(action, Action::Build(Structure::Crop(_), _)))
+	}
+
+	pub fn description(&self) -> &str {
+		content::item(*self)
+			.and_then(|def| def.description.as_deref())
+			.unwrap_or_else(|| self.builtin_description())
+	}
+
+	pub fn name(&self) -> &str {
+		content::item(*self)
+			.and_then(|def| def.name.as_deref())
+			.unwrap_or_else(|| self.builtin_name())
+	}
+
+	pub fn stack_size(self) -> usize {
+		content::item(self)
+			.and_then(|def| def.stack_size)
+			.unwrap_or_else(|| self.builtin_stack_size())
+	}
+
+	pub fn weapon(self) -> Option<Weapon> {
+		content::item(self)
+			.and_then(|def| def.weapon)
+			.or_else(|| self.builtin_weapon())
+	}
+
+	/// How much damage from each wound this item absorbs when worn
+	pub fn armour(self) -> i32 {
+		content::item(self)
+			.and_then(|def| def.armour)
+			.unwrap_or_else(|| self.builtin_armour())
+	}
+
+	/// Where the item goes when it is equipped
+	pub fn slot(self) -> Option<Slot> {
+		content::item(self)
+			.and_then(|def| def.slot)
+			.or_else(|| self.builtin_slot())
+	}
+
+	/// How many extra inventory slots this item gives when equipped
+	pub fn capacity(self) -> usize {
+		content::item(self)
+			.and_then(|def| def.capacity)
+			.unwrap_or_else(|| self.builtin_capacity())
+	}
+
+	/// How much energy eating this item gives. Items without food value can't be eaten
+	pub fn food(self) -> i32 {
+		content::item(self)
+			.and_then(|def| def.food)
+			.unwrap_or_else(|| self.builtin_food())
+	}
+
+	/// The number of uses before a tool breaks. Items without durability never wear down
+	pub fn durability(self) -> Option<u32> {
+		content::item(self)
+			.and_then(|def| def.durability)
+			.or_else(|| self.builtin_durability())
+	}
+}
+
+/// How an item performs when used to attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Weapon {
+	pub damage: i32,
+	pub reach: i32,
+	pub cooldown: Duration,
+}
 
 #[cfg(test)]
 mod tests {
@@ -180,3 +455,4 @@ mod tests {
 	}
 	
 }
+
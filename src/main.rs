@@ -1,12 +1,15 @@
 
 mod action;
 mod basemap;
+mod behaviour;
 mod config;
+mod content;
 mod controls;
 mod creature;
 mod creaturemap;
 mod creatures;
 mod crop;
+mod equipment;
 mod errors;
 mod gameserver;
 mod grid;
@@ -16,17 +19,20 @@ mod inventory;
 mod item;
 mod loadedareas;
 mod map;
+mod pathfinding;
 mod persistence;
 mod player;
 mod pos;
 mod random;
 mod randomtick;
+mod recipe;
 mod server;
 mod sprite;
 mod tile;
 mod tiledmap;
 mod timestamp;
 mod util;
+mod weather;
 mod world;
 mod worldmessages;
 
@@ -53,7 +59,9 @@ mod main {
 		persistence::{PersistentStorage, file::FileStorage, LoaderError},
 		config::{Config, WorldAction, WorldConfig, MapDef},
 		basemap::BaseMapImpl,
+		timestamp,
 		creature::PlayerSave,
+		content::{self, ContentPack},
 	};
 
 	pub fn main(){
@@ -62,6 +70,7 @@ mod main {
 
 		match config.world_action {
 			WorldAction::New{conf, mapdef} => {
+				load_content(&conf);
 				let persistence = FileStorage::initialize(&conf.name).unwrap();
 				if let Err(LoaderError::MissingResource(_)) = persistence.load_world() {
 					let basemap = BaseMapImpl::from_mapdef(mapdef.clone()).expect(&format!("Can't load base map {:?}", &mapdef));
@@ -71,6 +80,7 @@ mod main {
 				}
 			}
 			WorldAction::Load(conf) => {
+				load_content(&conf);
 				let persistence = FileStorage::initialize(&conf.name).unwrap();
 				let saved = persistence.load_world().expect("Can't load world");
 				let mapdef = &saved.mapdef;
@@ -92,11 +102,27 @@ mod main {
 		}
 	}
 
+	fn load_content(config: &WorldConfig) {
+		if let Some(path) = &config.content {
+			let pack = ContentPack::load(path).unwrap_or_else(|err| panic!("Can't load content pack {:?}: {}", path, err));
+			content::install(pack).unwrap_or_else(|err| panic!("{}", err));
+			eprintln!("loaded content pack {:?}", path);
+		}
+	}
+
 	fn start_world(mut world: World, persistence: FileStorage, config: WorldConfig) {
 
 		// eprintln!("stucture size: {}", std::mem::size_of::<crate::tile::Structure>());
 		// eprintln!("tile size: {}", std::mem::size_of::<crate::tile::Tile>());
 		eprintln!("Server admin(s): {}", config.admins);
+		if let Some(pvp) = config.pvp {
+			world.pvp = pvp;
+		}
+		eprintln!("PvP: {:?}", world.pvp);
+		if let Some(day_length) = config.day_length {
+			world.set_day_length(timestamp::Duration(day_length));
+		}
+		eprintln!("Day length: {} steps", world.day_length().0);
 
 		let adresses = config.address
 			.unwrap_or_else(||
@@ -1,22 +1,49 @@
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use serde::Serialize;
 use crate::{
 	pos::{Pos, Area, Direction},
-	tile::{Tile, Structure, Ground, TileView},
+	tile::{Tile, Structure, Ground, TileView, FERTILE},
 	basemap::{BaseMap, BaseMapImpl},
-	timestamp::{Timestamp, Duration},
+	timestamp::{Timestamp, Duration, DayPhase, DEFAULT_DAY_LENGTH, MIN_DAY_LENGTH},
 	creature::CreatureType as Npc,
 	item::Item,
+	crop::Crop,
+	weather::Weather,
+	random,
 	randomtick
 };
 
+/// How many channel tiles can fill with water in one tick
+const MAX_FLOW: usize = 32;
+/// How many channel tiles are searched for a connection to water
+const MAX_CHANNEL_SEARCH: usize = 256;
+/// How far from a sprinkler crops get watered
+const SPRINKLER_RADIUS: i32 = 2;
+/// Growing takes GROWTH_BASE / (GROWTH_BASE + growth speed) of the normal time
+const GROWTH_BASE: i64 = 4;
+/// How long soil has to lie fallow to regain a point of fertility
+const FALLOW_TIME: Duration = Duration(3000);
+/// The chance of a pest or disease outbreak for a crop that is surrounded by crops of the same kind.
+/// With fewer neighbours of the same kind the chance goes down accordingly
+const MAX_OUTBREAK_CHANCE: f32 = 0.01;
+/// How far away neighbouring crops still count towards an outbreak
+const OUTBREAK_RADIUS: i32 = 2;
+/// The chance in percent that pests or diseases spread to each neighbour
+const AFFLICTION_SPREAD_CHANCE: u32 = 30;
+/// The chance in percent that a blighted crop dies when it gets ticked
+const BLIGHT_DEATH_CHANCE: u32 = 10;
+/// The chance in percent that a fire spreads to each neighbour when it gets ticked
+const FIRE_SPREAD_CHANCE: u32 = 50;
+
 pub struct Map {
 	basemap: BaseMapImpl,
 	changes: HashMap<Pos, (Tile, Timestamp)>,
 	time: Timestamp,
+	day_length: Duration,
 	modifications: HashSet<Pos>,
-	spawns: Vec<(Pos, Npc)>
+	spawns: Vec<(Pos, Npc)>,
+	fireproof: Vec<Area>,
 }
 
 impl Map {
@@ -26,8 +53,10 @@ impl Map {
 			basemap,
 			changes: HashMap::new(),
 			time,
+			day_length: DEFAULT_DAY_LENGTH,
 			modifications: HashSet::new(),
 			spawns: Vec::new(),
+			fireproof: Vec::new(),
 		}
 	}
 	
@@ -61,21 +90,49 @@ impl Map {
 	}
 	
 	pub fn set_structure(&mut self, pos: Pos, structure: Structure) {
-		let new_tile = Tile::structure(self.cell(pos).ground, structure) ;
+		let new_tile = Tile { structure, ..self.cell(pos) };
 		self.set(pos, new_tile);
 	}
 	
 	pub fn set_ground(&mut self, pos: Pos, ground: Ground) {
-		let new_tile = Tile::structure(ground, self.cell(pos).structure);
+		let new_tile = Tile { ground, ..self.cell(pos) };
+		self.set(pos, new_tile);
+	}
+
+	pub fn set_fertility(&mut self, pos: Pos, fertility: u8) {
+		let new_tile = Tile { fertility, ..self.cell(pos) };
 		self.set(pos, new_tile);
 	}
 
+	/// Use up some of the fertility of the soil
+	pub fn exhaust(&mut self, pos: Pos) {
+		let fertility = self.cell(pos).fertility.saturating_sub(1);
+		self.set_fertility(pos, fertility);
+	}
+
 	pub fn take(&mut self, pos: Pos) -> Option<Item> {
 		let (new_tile, item) = self.cell(pos).take()?;
 		self.set(pos, new_tile);
 		Some(item)
 	}
 	
+	pub fn day_length(&self) -> Duration {
+		self.day_length
+	}
+
+	pub fn set_day_length(&mut self, day_length: Duration) {
+		self.day_length = day_length.max(MIN_DAY_LENGTH);
+	}
+
+	/// Fire won't spread into these areas
+	pub fn set_fireproof(&mut self, areas: Vec<Area>) {
+		self.fireproof = areas;
+	}
+
+	pub fn day_phase(&self) -> DayPhase {
+		self.time.day_phase(self.day_length)
+	}
+
 	pub fn player_spawn(&self) -> Pos {
 		self.basemap.player_spawn()
 	}
@@ -99,6 +156,8 @@ impl Map {
 			let old = self.cell(pos);
 			self.time = time;
 			self.tick_one(pos);
+			self.weather_one(pos);
+			self.afflict(pos);
 			if self.cell(pos) != old {
 				self.modifications.insert(pos);
 			}
@@ -106,11 +165,21 @@ impl Map {
 	}
 	
 	fn tick_one(&mut self, pos: Pos) {
+		self.burn(pos);
 		let tick_interval = randomtick::CHUNK_AREA;
 		if let Some((mut built, mut built_time)) = self.changes.get(&pos) {
 			while let Some((nticks, stage, surround)) = built.grow() {
-				let update_time = built_time + Duration(nticks * tick_interval);
-				if update_time <= self.time {
+				let duration = Duration(nticks * tick_interval * GROWTH_BASE / (GROWTH_BASE + built.growth_speed()));
+				let update_time = if built.needs_daylight() {
+						built_time.after_daylight(duration, self.day_length)
+					} else {
+						built_time + duration
+					};
+				if update_time <= self.time && !built.grows_in(update_time.season()) {
+					// wait for the next season and start growing again from there
+					built_time = update_time.next_season();
+					self.changes.insert(pos, (built, built_time));
+				} else if update_time <= self.time {
 					built.structure = stage;
 					built_time = update_time;
 					self.changes.insert(pos, (built, built_time));
@@ -118,7 +187,7 @@ impl Map {
 						for d in Direction::DIRECTIONS {
 							let npos = pos + d;
 							let mut ntile = self.cell(npos);
-							if let Some(product) = shoot.joined(ntile.structure) {
+							if let Some(product) = shoot.joined(ntile.structure, random::randomize_pos(npos) ^ built_time.random_seed()) {
 								ntile.structure = product;
 								self.changes.insert(npos, (ntile, built_time));
 								self.modifications.insert(npos);
@@ -133,18 +202,192 @@ impl Map {
 					break
 				}
 			}
-			if built.structure.is_open() {
+			if built.structure.is_open() && built.fertility < FERTILE {
+				let recovered = (self.time - built_time).0 / FALLOW_TIME.0;
+				if recovered > 0 {
+					built.fertility = (built.fertility as i64 + recovered).min(FERTILE as i64) as u8;
+					built_time = built_time + Duration(recovered * FALLOW_TIME.0);
+					self.changes.insert(pos, (built, built_time));
+				}
+			}
+			if built.structure.is_open() && built.fertility == FERTILE {
 				let base_cell = self.base_cell(pos);
 				if  (built.ground.restoring() || built.ground == base_cell.ground) && base_cell.structure.is_open() {
 					self.changes.remove(&pos);
 				}
 			}
 		}
-		if let Some(npc) = self.cell(pos).spawn() {
+		self.flow(pos);
+		self.sprinkle(pos);
+		if let Some(npc) = self.cell(pos).spawn(self.day_phase()) {
 			self.spawns.push((pos, npc));
 		}
 	}
+
+	/// Fires scorch the ground below them and spread to their flammable neighbours
+	fn burn(&mut self, pos: Pos) {
+		let Some((tile, time)) = self.changes.get(&pos).copied() else {
+			return;
+		};
+		if !tile.structure.burning() {
+			return;
+		}
+		if let Some(scorched) = tile.ground.clear() {
+			self.changes.insert(pos, (Tile { ground: scorched, ..tile }, time));
+			self.modifications.insert(pos);
+		}
+		let rind = random::randomize_pos(pos) ^ self.time.random_seed();
+		for (i, direction) in Direction::DIRECTIONS.into_iter().enumerate() {
+			let target = pos + direction;
+			if self.fireproof.iter().any(|area| area.contains(target))
+					|| !random::percentage(rind.wrapping_add(i as u32), FIRE_SPREAD_CHANCE) {
+				continue;
+			}
+			if let Some(fire) = self.cell(target).ignite() {
+				self.set(target, fire);
+			}
+		}
+	}
+
+	/// Fill dry channels that are connected to water and dry up channels that are cut off from it
+	fn flow(&mut self, pos: Pos) {
+		if !self.changes.get(&pos).is_some_and(|(tile, _)| tile.ground.is_channel()) {
+			return;
+		}
+		match self.channel_network(pos) {
+			(_, Some(true)) => {}
+			(network, Some(false)) => {
+				for p in network {
+					if self.cell(p).ground == Ground::ChannelWater {
+						self.set_ground(p, Ground::Channel);
+					}
+				}
+				return;
+			}
+			(_, None) => return,
+		}
+		let mut queue = VecDeque::from([pos]);
+		let mut filled = 0;
+		while let Some(current) = queue.pop_front() {
+			for next in [current].into_iter().chain(Direction::DIRECTIONS.map(|d| current + d)) {
+				if filled < MAX_FLOW && self.cell(next).ground == Ground::Channel {
+					self.set_ground(next, Ground::ChannelWater);
+					filled += 1;
+					queue.push_back(next);
+				}
+			}
+		}
+	}
+
+	/// The channels connected to this one, and whether any of them touches water that isn't in a channel.
+	/// When the search runs out before the whole network is found, the connection is unknown
+	fn channel_network(&self, pos: Pos) -> (Vec<Pos>, Option<bool>) {
+		let mut network = vec![pos];
+		let mut seen = HashSet::from([pos]);
+		let mut queue = VecDeque::from([pos]);
+		while let Some(current) = queue.pop_front() {
+			for next in Direction::DIRECTIONS.map(|d| current + d) {
+				let ground = self.cell(next).ground;
+				if !ground.is_channel() {
+					if ground.has_water() {
+						return (network, Some(true));
+					}
+					continue;
+				}
+				if seen.insert(next) {
+					if network.len() >= MAX_CHANNEL_SEARCH {
+						return (network, None);
+					}
+					network.push(next);
+					queue.push_back(next);
+				}
+			}
+		}
+		(network, Some(false))
+	}
+
+	/// Sprinklers next to water water all crops around them
+	fn sprinkle(&mut self, pos: Pos) {
+		if !self.changes.get(&pos).is_some_and(|(tile, _)| tile.structure == Structure::Sprinkler) {
+			return;
+		}
+		if !Direction::DIRECTIONS.into_iter().any(|d| self.cell(pos + d).ground.has_water()) {
+			return;
+		}
+		let size = SPRINKLER_RADIUS * 2 + 1;
+		for p in Area::centered(pos, Pos::new(size, size)).iter() {
+			if let Structure::Crop(crop) = self.cell(p).structure {
+				if crop.water() != crop {
+					self.set_structure(p, Structure::Crop(crop.water()));
+				}
+			}
+		}
+	}
 	
+	/// Crops are always in the open, so the weather reaches them
+	fn weather_one(&mut self, pos: Pos) {
+		let structure = self.cell(pos).structure;
+		if structure.burning() && self.weather(pos).waters() {
+			self.set_structure(pos, Structure::Air);
+		}
+		if let Structure::Crop(crop) = structure {
+			let weathered = crop.weather(self.weather(pos));
+			if weathered != crop {
+				self.set_structure(pos, Structure::Crop(weathered));
+			}
+		}
+	}
+
+	/// Pests and diseases break out in crowded fields of a single crop and spread from there
+	fn afflict(&mut self, pos: Pos) {
+		let Structure::Crop(crop) = self.cell(pos).structure else {
+			return;
+		};
+		let rind = random::randomize_pos(pos) ^ self.time.random_seed();
+		if !crop.is_blighted() && !crop.is_infested() {
+			let size = OUTBREAK_RADIUS * 2 + 1;
+			let same_kind = Area::centered(pos, Pos::new(size, size))
+				.iter()
+				.filter(|p| *p != pos && matches!(self.cell(*p).structure, Structure::Crop(other) if other.same_kind(crop)))
+				.count();
+			let chance = same_kind as f32 / (size * size - 1) as f32 * MAX_OUTBREAK_CHANCE;
+			if random::random_float(rind) < chance {
+				let afflicted = if rind & 1 == 0 { crop.blight() } else { crop.infest() };
+				self.set_crop(pos, afflicted);
+			}
+			return;
+		}
+		for (i, direction) in Direction::DIRECTIONS.into_iter().enumerate() {
+			let target = pos + direction;
+			let Structure::Crop(other) = self.cell(target).structure else {
+				continue;
+			};
+			if !random::percentage(rind.wrapping_add(i as u32 + 1), AFFLICTION_SPREAD_CHANCE) {
+				continue;
+			}
+			if crop.is_blighted() {
+				self.set_crop(target, other.blight());
+			} else if other.same_kind(crop) {
+				self.set_crop(target, other.infest());
+			}
+		}
+		if crop.is_blighted() && random::percentage(rind.wrapping_add(17), BLIGHT_DEATH_CHANCE) {
+			self.set_structure(pos, Structure::Air);
+		}
+	}
+
+	/// Change the condition of a crop without restarting its growth
+	fn set_crop(&mut self, pos: Pos, crop: Crop) {
+		let built_time = self.changes.get(&pos).map_or(self.time, |(_, time)| *time);
+		let tile = Tile { structure: Structure::Crop(crop), ..self.cell(pos) };
+		self.changes.insert(pos, (tile, built_time));
+		self.modifications.insert(pos);
+	}
+
+	pub fn weather(&self, pos: Pos) -> Weather {
+		Weather::at(self.basemap.seed(), pos, self.time)
+	}
+
 	pub fn flush(&mut self) {
 		self.modifications.clear();
 		self.spawns.clear();
@@ -165,10 +408,14 @@ impl Map {
 	pub fn load(changes: MapSave, time: Timestamp, basemap: BaseMapImpl) -> Self {
 		Self {
 			basemap,
-			changes: changes.into_iter().collect(),
+			changes: changes.into_iter()
+				.map(|(pos, (tile, time))| (pos, (Tile { structure: tile.structure.migrated(), ..tile }, time)))
+				.collect(),
 			time,
+			day_length: DEFAULT_DAY_LENGTH,
 			modifications: HashSet::new(),
 			spawns: Vec::new(),
+			fireproof: Vec::new(),
 		}
 	}
 
@@ -203,3 +450,174 @@ pub struct SectionView {
 
 pub type MapSave = Vec<(Pos, (Tile, Timestamp))>;
 
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		infinitemap::InfiniteMap,
+		crop::CropType,
+	};
+
+	#[test]
+	fn water_flows_a_limited_distance() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		map.set(Pos::new(0, 0), Tile::ground(Ground::Water));
+		for x in 1..(MAX_FLOW as i32 + 5) {
+			map.set(Pos::new(x, 0), Tile::ground(Ground::Channel));
+		}
+		map.tick_one(Pos::new(1, 0));
+		assert_eq!(map.cell(Pos::new(MAX_FLOW as i32, 0)).ground, Ground::ChannelWater);
+		assert_eq!(map.cell(Pos::new(MAX_FLOW as i32 + 1, 0)).ground, Ground::Channel);
+	}
+
+	#[test]
+	fn cut_off_channels_dry_up() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		map.set(Pos::new(0, 0), Tile::ground(Ground::Water));
+		for x in 1..5 {
+			map.set(Pos::new(x, 0), Tile::ground(Ground::Channel));
+		}
+		map.tick_one(Pos::new(1, 0));
+		assert_eq!(map.cell(Pos::new(4, 0)).ground, Ground::ChannelWater);
+		map.set(Pos::new(0, 0), Tile::ground(Ground::Dirt));
+		map.tick_one(Pos::new(3, 0));
+		assert!((1..5).all(|x| map.cell(Pos::new(x, 0)).ground == Ground::Channel));
+	}
+
+	#[test]
+	fn sprinkler_needs_water() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		map.set(Pos::new(0, 0), Tile::structure(Ground::Dirt, Structure::Sprinkler));
+		map.set(Pos::new(2, 1), Tile::structure(Ground::Dirt, Structure::Crop(Crop::greenseed())));
+		map.tick_one(Pos::new(0, 0));
+		assert_eq!(map.cell(Pos::new(2, 1)).structure, Structure::Crop(Crop::greenseed()));
+		map.set(Pos::new(-1, 0), Tile::ground(Ground::ChannelWater));
+		map.tick_one(Pos::new(0, 0));
+		assert_eq!(map.cell(Pos::new(2, 1)).structure, Structure::Crop(Crop::greenseed().water()));
+	}
+
+	#[test]
+	fn planted_sapling_grows_old() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		let pos = Pos::new(0, 0);
+		map.set(pos, Tile::structure(Ground::Dirt, Structure::Sapling));
+		map.time = Timestamp(randomtick::CHUNK_AREA * 3);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).structure, Structure::Sapling);
+		map.time = Timestamp(DEFAULT_DAY_LENGTH.0 * 3);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).structure, Structure::Tree);
+		map.time = Timestamp(DEFAULT_DAY_LENGTH.0 * 1000);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).structure, Structure::OldTreeTinder);
+	}
+
+	#[test]
+	fn old_seeding_plants_become_crops() {
+		let saved = vec![(Pos::new(0, 0), (Tile::structure(Ground::Dirt, Structure::SeedingKnifePlant), Timestamp(0)))];
+		let map = Map::load(saved, Timestamp(0), InfiniteMap::new(1).into());
+		assert_eq!(map.cell(Pos::new(0, 0)).structure, Structure::Crop(Crop::new(CropType::SeedingKnifePlant)));
+	}
+
+	#[test]
+	fn day_length_stays_positive() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		map.set_day_length(Duration(0));
+		assert_eq!(map.day_length(), MIN_DAY_LENGTH);
+		map.day_phase();
+	}
+
+	#[test]
+	fn picked_bush_grows_berries_again() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		let pos = Pos::new(0, 0);
+		let picked = Tile::structure(Ground::Grass1, Structure::Bush).interact(Item::Nothing, 0).unwrap();
+		assert_eq!(picked.remains, Some(Structure::PickedBush));
+		map.set(pos, Tile::structure(Ground::Grass1, Structure::PickedBush));
+		map.time = Timestamp(DEFAULT_DAY_LENGTH.0 * 2);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).structure, Structure::Bush);
+	}
+
+	#[test]
+	fn exhausted_soil_recovers_when_fallow() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		let pos = Pos::new(0, 0);
+		map.set(pos, Tile::structure(Ground::Dirt, Structure::Crop(Crop::greenseed().water())));
+		for _ in 0..FERTILE {
+			map.exhaust(pos);
+		}
+		map.time = Timestamp(DEFAULT_DAY_LENGTH.0 * 3);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).structure, Structure::Crop(Crop::greenseed().water()));
+		map.set_structure(pos, Structure::Air);
+		map.time = map.time + Duration(FALLOW_TIME.0 * 2);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).fertility, 2);
+		map.time = map.time + Duration(FALLOW_TIME.0 * 10);
+		map.tick_one(pos);
+		assert_eq!(map.cell(pos).fertility, FERTILE);
+	}
+
+	#[test]
+	fn monocultures_get_sick() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		let lonely = Pos::new(-10, 0);
+		map.set(lonely, Tile::structure(Ground::Dirt, Structure::Crop(Crop::greenseed())));
+		for pos in Area::new(Pos::new(0, 0), Pos::new(5, 5)).iter() {
+			map.set(pos, Tile::structure(Ground::Dirt, Structure::Crop(Crop::greenseed())));
+		}
+		for t in 0..1000 {
+			map.time = Timestamp(t);
+			map.afflict(lonely);
+			map.afflict(Pos::new(2, 2));
+		}
+		assert_eq!(map.cell(lonely).structure, Structure::Crop(Crop::greenseed()));
+		assert_ne!(map.cell(Pos::new(2, 2)).structure, Structure::Crop(Crop::greenseed()));
+	}
+
+	#[test]
+	fn blight_spreads_and_kills() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		map.set(Pos::new(0, 0), Tile::structure(Ground::Dirt, Structure::Crop(Crop::greenseed().blight())));
+		map.set(Pos::new(1, 0), Tile::structure(Ground::Dirt, Structure::Crop(Crop::brownseed())));
+		let blighted = |map: &Map| map.cell(Pos::new(1, 0)).structure == Structure::Crop(Crop::brownseed().blight());
+		for t in 1..1000 {
+			map.time = Timestamp(t);
+			map.afflict(Pos::new(0, 0));
+			if blighted(&map) {
+				break;
+			}
+			if map.cell(Pos::new(0, 0)).structure == Structure::Air {
+				map.set(Pos::new(0, 0), Tile::structure(Ground::Dirt, Structure::Crop(Crop::greenseed().blight())));
+			}
+		}
+		assert!(blighted(&map));
+		assert_eq!(map.changes[&Pos::new(1, 0)].1, Timestamp(0));
+		for t in 1000..2000 {
+			map.time = Timestamp(t);
+			map.afflict(Pos::new(1, 0));
+		}
+		assert_eq!(map.cell(Pos::new(1, 0)).structure, Structure::Air);
+	}
+
+	#[test]
+	fn fire_spreads_outside_fireproof_areas() {
+		let mut map = Map::new(InfiniteMap::new(1).into(), Timestamp(0));
+		for x in -20..=20 {
+			map.set(Pos::new(x, 0), Tile::structure(Ground::Grass1, Structure::Shrub));
+		}
+		map.set(Pos::new(0, 0), Tile::structure(Ground::Grass1, Structure::BrushFire));
+		map.set_fireproof(vec![Area::new(Pos::new(-20, -1), Pos::new(20, 3))]);
+		for t in 0..40 {
+			map.time = Timestamp(t);
+			for x in -20..=20 {
+				map.tick_one(Pos::new(x, 0));
+			}
+		}
+		assert_eq!(map.cell(Pos::new(0, 0)).ground, Ground::Dirt);
+		assert!((1..=20).any(|x| map.cell(Pos::new(x, 0)).structure.burning()));
+		assert!((-20..0).all(|x| map.cell(Pos::new(x, 0)).structure == Structure::Shrub));
+	}
+}
@@ -0,0 +1,91 @@
+
+use std::collections::{BinaryHeap, HashMap};
+use std::cmp::Reverse;
+use crate::pos::{Pos, Direction};
+
+/// How many positions a player's path search may visit
+pub const PLAYER_BUDGET: usize = 2048;
+/// How many positions an npc's path search may visit
+pub const NPC_BUDGET: usize = 256;
+
+/// Find a shortest path from start to goal using A*.
+/// The returned path excludes the start and ends at the goal.
+/// Gives up when more than `budget` positions have been visited.
+pub fn find_path(start: Pos, goal: Pos, budget: usize, walkable: impl Fn(Pos) -> bool) -> Option<Vec<Pos>> {
+	let mut open = BinaryHeap::new();
+	let mut came_from: HashMap<Pos, Pos> = HashMap::new();
+	let mut costs: HashMap<Pos, i32> = HashMap::new();
+	costs.insert(start, 0);
+	open.push(Reverse((start.distance_to(goal), 0, start.x, start.y)));
+	let mut visited = 0;
+	while let Some(Reverse((_, cost, x, y))) = open.pop() {
+		let pos = Pos::new(x, y);
+		if pos == goal {
+			return Some(reconstruct(&came_from, start, goal));
+		}
+		if costs.get(&pos).is_some_and(|best| *best < cost) {
+			continue;
+		}
+		visited += 1;
+		if visited > budget {
+			return None;
+		}
+		for direction in Direction::DIRECTIONS {
+			let next = pos + direction;
+			if !walkable(next) {
+				continue;
+			}
+			let next_cost = cost + 1;
+			if costs.get(&next).is_none_or(|best| next_cost < *best) {
+				costs.insert(next, next_cost);
+				came_from.insert(next, pos);
+				open.push(Reverse((next_cost + next.distance_to(goal), next_cost, next.x, next.y)));
+			}
+		}
+	}
+	None
+}
+
+fn reconstruct(came_from: &HashMap<Pos, Pos>, start: Pos, goal: Pos) -> Vec<Pos> {
+	let mut path = Vec::new();
+	let mut pos = goal;
+	while pos != start {
+		path.push(pos);
+		pos = came_from[&pos];
+	}
+	path.reverse();
+	path
+}
+
+/// The first direction to walk in to get from start to goal
+pub fn first_step(start: Pos, goal: Pos, budget: usize, walkable: impl Fn(Pos) -> bool) -> Option<Direction> {
+	let next = *find_path(start, goal, budget, walkable)?.first()?;
+	start.directions_to(next).first().copied()
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn walks_around_wall() {
+		let wall = |p: Pos| p.x == 2 && p.y >= -3 && p.y <= 3;
+		let path = find_path(Pos::new(0, 0), Pos::new(4, 0), 1000, |p| !wall(p)).unwrap();
+		assert_eq!(path.len(), 12);
+		assert_eq!(path.last(), Some(&Pos::new(4, 0)));
+		assert!(path.iter().all(|p| !wall(*p)));
+		assert!(path.windows(2).all(|w| w[0].distance_to(w[1]) == 1));
+	}
+
+	#[test]
+	fn gives_up_when_enclosed() {
+		let enclosed = |p: Pos| p.distance_to(Pos::new(20, 0)) == 3;
+		assert_eq!(find_path(Pos::new(0, 0), Pos::new(20, 0), 500, |p| !enclosed(p)), None);
+	}
+
+	#[test]
+	fn empty_path_to_self() {
+		assert_eq!(find_path(Pos::new(3, 3), Pos::new(3, 3), 0, |_| true), Some(Vec::new()));
+	}
+}
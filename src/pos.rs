@@ -245,6 +245,10 @@ impl Area {
 	pub fn between(min: Pos, max: Pos) -> Self {
 		Self::new(min, max - min)
 	}
+
+	pub fn center(&self) -> Pos {
+		self.min + self.size / 2
+	}
 	
 	pub fn min(&self) -> Pos {
 		self.min
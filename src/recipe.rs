@@ -0,0 +1,93 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::{
+	action::{Action, CraftType},
+	item::Item,
+	inventory::Inventory,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecipeId {
+	pub station: CraftType,
+	pub product: Item,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recipe {
+	pub id: RecipeId,
+	pub tool: Item,
+	pub ingredients: HashMap<Item, usize>,
+}
+
+impl Recipe {
+
+	pub fn from_action(tool: Item, action: Action) -> Option<Self> {
+		let Action::Craft(station, product, mut ingredients) = action else {
+			return None;
+		};
+		ingredients.entry(tool).and_modify(|n| {*n += 1;}).or_insert(1);
+		Some(Self {
+			id: RecipeId { station, product },
+			tool,
+			ingredients,
+		})
+	}
+
+	pub fn all() -> Vec<Recipe> {
+		Item::ALL.into_iter()
+			.flat_map(|tool| tool.actions().into_iter().filter_map(move |action| Self::from_action(tool, action)))
+			.collect()
+	}
+
+	pub fn uses(&self, item: Item) -> bool {
+		self.ingredients.contains_key(&item)
+	}
+
+	pub fn view(&self, inventory: &Inventory) -> RecipeView {
+		let mut ingredients: Vec<(String, usize)> = self.ingredients.iter()
+			.map(|(item, n)| (item.name().to_string(), *n))
+			.collect();
+		ingredients.sort();
+		RecipeView {
+			station: self.id.station,
+			product: self.id.product.name().to_string(),
+			tool: self.tool.name().to_string(),
+			ingredients,
+			affordable: inventory.can_afford(&self.ingredients),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecipeView {
+	station: CraftType,
+	product: String,
+	tool: String,
+	ingredients: Vec<(String, usize)>,
+	affordable: bool,
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn marker_stone_needs_flowers_and_stone() {
+		let recipe = Recipe::all().into_iter()
+			.find(|recipe| recipe.id.product == Item::MarkerStone)
+			.unwrap();
+		assert_eq!(recipe.id.station, CraftType::Marker);
+		assert_eq!(recipe.ingredients, crate::hashmap![Item::Stone => 1, Item::Flower => 10]);
+	}
+
+	#[test]
+	fn affordable_with_ingredients() {
+		let recipe = Recipe::all().into_iter()
+			.find(|recipe| recipe.id.product == Item::Hoe)
+			.unwrap();
+		assert!(!recipe.view(&Inventory::load(vec![(Item::Stick, 1), (Item::Reed, 1)])).affordable);
+		assert!(recipe.view(&Inventory::load(vec![(Item::Stick, 1), (Item::Reed, 1), (Item::SharpStone, 1)])).affordable);
+	}
+}
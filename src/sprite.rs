@@ -83,7 +83,20 @@ pub enum Sprite {
 
 	SawTable,
 
+	Slime,
+	Hide,
+
+	Channel,
+	ChannelWater,
+	Sprinkler,
+
+	CompostBin,
+	CompostHeap,
+	CompostReady,
+
 	Frog,
 	Worm,
+	Sheep,
+	Hen,
 	Unknown,
 }
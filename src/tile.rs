@@ -5,26 +5,33 @@ use crate::{
 	sprite::Sprite,
 	item::Item,
 	action::{Action, InteractionType, CraftType, Interactable, InteractionResult},
-	timestamp::Timestamp,
+	recipe::RecipeId,
+	timestamp::{DayPhase, Season},
 	hashmap,
-	crop::Crop,
+	crop::{Crop, CropType},
 	creature::CreatureType as Npc,
+	content,
 };
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Assoc, Serialize, Deserialize)]
 #[func(fn sprite(self) -> Option<Sprite>)]
 #[func(fn accessible(self) -> bool {true})]
-#[func(fn clear(self) -> Option<Ground>)]
+#[func(pub fn clear(self) -> Option<Ground>)]
+#[func(fn dig(self) -> Option<Ground>)]
 #[func(fn describe(&self) -> Option<&str>)]
 #[func(fn craft(self) -> Option<CraftType>)]
 #[func(fn buildable(self) -> bool {false})]
 #[func(pub fn restoring(self) -> bool {false})]
+#[func(pub fn safe(self) -> bool {false})]
+#[func(pub fn has_water(self) -> bool {false})]
+#[func(pub fn is_channel(self) -> bool {false})]
 pub enum Ground {
 	#[assoc(sprite = Sprite::Dirt)]
 	#[assoc(describe = "Dirt")]
 	#[assoc(buildable = true)]
 	#[assoc(restoring = true)]
+	#[assoc(dig = Ground::Channel)]
 	Dirt,
 	
 	#[assoc(clear = Ground::Dirt)]
@@ -54,6 +61,7 @@ pub enum Ground {
 	
 	#[assoc(sprite = Sprite::Sanctuary)]
 	#[assoc(describe = "Ornate stone floor")]
+	#[assoc(safe = true)]
 	Sanctuary,
 	
 	#[assoc(sprite = Sprite::Water)]
@@ -63,6 +71,20 @@ pub enum Ground {
 	#[assoc(craft = CraftType::Water)]
 	Water,
 	
+	#[assoc(sprite = Sprite::Channel)]
+	#[assoc(clear = Ground::Dirt)]
+	#[assoc(describe = "Irrigation channel. It fills with water when it is connected to water")]
+	#[assoc(is_channel = true)]
+	Channel,
+	
+	#[assoc(sprite = Sprite::ChannelWater)]
+	#[assoc(has_water = true)]
+	#[assoc(clear = Ground::Dirt)]
+	#[assoc(describe = "Irrigation channel filled with water")]
+	#[assoc(craft = CraftType::Water)]
+	#[assoc(is_channel = true)]
+	ChannelWater,
+	
 	#[assoc(sprite = Sprite::RockFloor)]
 	#[assoc(buildable = true)]
 	#[assoc(describe = "Rock floor")]
@@ -82,18 +104,25 @@ pub enum Ground {
 }
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Assoc, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Assoc, Serialize, Deserialize)]
 #[func(fn sprite(self) -> Option<Sprite>)]
 #[func(fn blocking(self) -> bool {false})]
 #[func(pub fn is_open(self) -> bool {false})]
-#[func(fn interactions(self) -> Vec<Interactable> {Vec::new()})]
+#[func(fn builtin_interactions(self) -> Vec<Interactable> {Vec::new()})]
 #[func(fn take(self) -> Option<Item>)]
 #[func(fn describe(&self) -> Option<&str>)]
-#[func(fn description(self) -> Option<String> { self.describe().map(|s| s.to_string())})]
+#[func(fn builtin_description(self) -> Option<String> { self.describe().map(|s| s.to_string())})]
 #[func(fn craft(self) -> Option<CraftType>)]
-#[func(fn grow(self) -> Option<(i64, Structure, Option<Structure>)>)]
-#[func(fn join(self, other: Structure) -> Option<Structure>)]
+#[func(fn builtin_grow(self) -> Option<(i64, Structure, Option<Structure>)>)]
+#[func(fn join(self, other: Structure, rind: u32) -> Option<Structure>)]
 #[func(fn spawn(self) -> Option<Npc>)]
+#[func(fn spawn_phase(self) -> Option<DayPhase>)]
+#[func(fn needs_daylight(self) -> bool {false})]
+#[func(fn grows_in(self, season: Season) -> bool {true})]
+#[func(fn burn(self) -> Option<Structure>)]
+#[func(fn growth_speed(self) -> i64 {0})]
+#[func(fn needs_soil(self) -> bool {false})]
+#[func(pub fn burning(self) -> bool {false})]
 pub enum Structure {
 	#[assoc(is_open = true)]
 	Air,
@@ -106,6 +135,7 @@ pub enum Structure {
 	#[assoc(sprite = Sprite::WoodWall)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Wooden wall")]
+	#[assoc(burn = Structure::Blaze)]
 	WoodWall,
 	
 	#[assoc(sprite = Sprite::Rock)]
@@ -119,82 +149,115 @@ pub enum Structure {
 	RockMid,
 	
 	#[assoc(sprite = Sprite::Sapling)]
-	#[assoc(describe = "Sapling")]
+	#[assoc(describe = "Sapling. Will grow into a tree")]
+	#[assoc(take = Item::Sapling)]
+	#[assoc(needs_daylight = true)]
+	#[assoc(builtin_grow = (4, Structure::YoungTree, None))]
+	#[assoc(burn = Structure::BrushFire)]
 	Sapling,
 	
 	#[assoc(sprite = Sprite::YoungTree)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Young tree")]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Stick])])]
+	#[assoc(needs_daylight = true)]
+	#[assoc(builtin_grow = (8, Structure::Tree, None))]
+	#[assoc(builtin_interactions = vec![
+		Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Stick]).with_extra(Item::Sapling, 0.2)
+	])]
+	#[assoc(burn = Structure::Blaze)]
 	YoungTree,
 	
 	#[assoc(sprite = Sprite::Tree)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Tree")]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Log])])]
+	#[assoc(builtin_grow = (96, Structure::OldTree, None))]
+	#[assoc(builtin_interactions = vec![
+		Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Log]).with_extra(Item::Sapling, 0.4)
+	])]
+	#[assoc(burn = Structure::Blaze)]
 	Tree,
 	
 	#[assoc(sprite = Sprite::OldTree)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Dead tree")]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Stick])])]
+	#[assoc(builtin_grow = (16, Structure::OldTreeTinder, None))]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Stick])])]
+	#[assoc(burn = Structure::Blaze)]
 	OldTree,
 	
 	#[assoc(sprite = Sprite::OldTreeTinder)]
 	#[assoc(blocking = true)]
-	#[assoc(interactions = vec![Interactable::new(InteractionType::Cut, 1, &[0.5, 1.0], Some(Structure::OldTree), &[Item::Tinder])])]
+	#[assoc(builtin_interactions = vec![Interactable::new(InteractionType::Cut, 1, &[0.5, 1.0], Some(Structure::OldTree), &[Item::Tinder])])]
 	#[assoc(describe = "Dead tree with tinder fungus on it")]
+	#[assoc(burn = Structure::Blaze)]
 	OldTreeTinder,
 	
 	#[assoc(sprite = Sprite::DenseGrass)]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Take, 0, &[0.1], &[Item::GreenSeed])])]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Take, 0, &[0.1], &[Item::GreenSeed])])]
 	#[assoc(describe = "Dense grass")]
+	#[assoc(burn = Structure::BrushFire)]
 	DenseGrassGrn,
 	
 	#[assoc(sprite = Sprite::DenseGrass)]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Take, 0, &[0.1], &[Item::BrownSeed])])]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Take, 0, &[0.1], &[Item::BrownSeed])])]
 	#[assoc(describe = "Dense grass")]
+	#[assoc(burn = Structure::BrushFire)]
 	DenseGrassBrn,
 	
 	#[assoc(sprite = Sprite::DenseGrass)]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Take, 0, &[0.1], &[Item::YellowSeed])])]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Take, 0, &[0.1], &[Item::YellowSeed])])]
 	#[assoc(describe = "Dense grass")]
+	#[assoc(burn = Structure::BrushFire)]
 	DenseGrassY,
 	
 	#[assoc(sprite = Sprite::Heather)]
 	#[assoc(describe = "Heather")]
+	#[assoc(burn = Structure::BrushFire)]
 	Heather,
 	
 	#[assoc(sprite = Sprite::Rush)]
 	#[assoc(describe = "Rush")]
+	#[assoc(burn = Structure::BrushFire)]
 	Rush,
 	
 	#[assoc(sprite = Sprite::Shrub)]
 	#[assoc(describe = "Some shrub")]
+	#[assoc(burn = Structure::BrushFire)]
 	Shrub,
 	
 	#[assoc(sprite = Sprite::Bush)]
-	#[assoc(describe = "Just a bush")]
+	#[assoc(describe = "A bush with some berries on it")]
+	#[assoc(builtin_interactions = vec![Interactable::new(InteractionType::Take, 0, &[], Some(Structure::PickedBush), &[Item::Berries])])]
+	#[assoc(burn = Structure::BrushFire)]
 	Bush,
 	
+	#[assoc(sprite = Sprite::Shrub)]
+	#[assoc(describe = "A bush that has been picked. The berries will grow back")]
+	#[assoc(needs_daylight = true)]
+	#[assoc(builtin_grow = (4, Structure::Bush, None))]
+	#[assoc(burn = Structure::BrushFire)]
+	PickedBush,
+	
 	#[assoc(sprite = Sprite::Reed)]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Cut, 1, &[0.5, 1.0], &[Item::Reed])])]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Cut, 1, &[0.5, 1.0], &[Item::Reed])])]
 	#[assoc(describe = "Reeds. Can be cut")]
+	#[assoc(burn = Structure::BrushFire)]
 	Reed,
 	
 	#[assoc(sprite = Sprite::PitcherPlant)]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Cut, 1, &[0.5, 1.0], &[Item::Pitcher])])]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Cut, 1, &[0.5, 1.0], &[Item::Pitcher])])]
 	#[assoc(describe = "Pitcher plant. Can be cut")]
 	PitcherPlant,
 	
 	#[assoc(sprite = Sprite::Flower)]
 	#[assoc(take = Item::Flower)]
 	#[assoc(describe = "Flower")]
+	#[assoc(burn = Structure::BrushFire)]
 	Flower,
 	
 	#[assoc(sprite = Sprite::Stone)]
 	#[assoc(take = Item::Stone)]
-	#[assoc(interactions = vec![
+	#[assoc(builtin_interactions = vec![
 		Interactable::new(
 			InteractionType::Smash,
 			1,
@@ -218,21 +281,56 @@ pub enum Structure {
 	#[assoc(sprite = Sprite::Fireplace)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Fireplace. Safe place to have a fire")]
-	#[assoc(interactions = vec![Interactable::transform(InteractionType::Fuel, 1, Structure::Fire)])]
+	#[assoc(builtin_interactions = vec![Interactable::transform(InteractionType::Fuel, 1, Structure::Fire)])]
 	Fireplace,
 	
 	#[assoc(sprite = Sprite::Fire)]
 	#[assoc(blocking = true)]
-	#[assoc(describe = "Fire. Safely contained in fireplace.")]
-	#[assoc(grow = (1, Structure::AshPlace, None))]
+	#[assoc(describe = "Fire. Safely contained in fireplace. Food can be cooked on it")]
+	#[assoc(craft = CraftType::Cooking)]
+	#[assoc(builtin_grow = (1, Structure::AshPlace, None))]
 	Fire,
 	
+	#[assoc(sprite = Sprite::Fire)]
+	#[assoc(describe = "Burning brush. It will leave bare ground behind")]
+	#[assoc(burning = true)]
+	#[assoc(builtin_grow = (1, Structure::Air, None))]
+	#[assoc(builtin_interactions = vec![Interactable::transform(InteractionType::Water, 1, Structure::Air)])]
+	BrushFire,
+	
+	#[assoc(sprite = Sprite::Fire)]
+	#[assoc(blocking = true)]
+	#[assoc(describe = "A big fire. It will leave only ash behind")]
+	#[assoc(burning = true)]
+	#[assoc(builtin_grow = (1, Structure::Item(Item::Ash), None))]
+	#[assoc(builtin_interactions = vec![Interactable::transform(InteractionType::Water, 1, Structure::Air)])]
+	Blaze,
+	
 	#[assoc(sprite = Sprite::AshPlace)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Fireplace. Filled with ash")]
-	#[assoc(interactions = vec![Interactable::new(InteractionType::Take, 0, &[], Some(Structure::Fireplace), &[Item::Ash])])]
+	#[assoc(builtin_interactions = vec![Interactable::new(InteractionType::Take, 0, &[], Some(Structure::Fireplace), &[Item::Ash])])]
 	AshPlace,
 	
+	#[assoc(sprite = Sprite::CompostBin)]
+	#[assoc(blocking = true)]
+	#[assoc(describe = "Compost bin. Put plant waste in it to make compost")]
+	#[assoc(builtin_interactions = vec![Interactable::transform(InteractionType::Compost, 1, Structure::CompostHeap)])]
+	#[assoc(burn = Structure::Blaze)]
+	CompostBin,
+	
+	#[assoc(sprite = Sprite::CompostHeap)]
+	#[assoc(blocking = true)]
+	#[assoc(describe = "Compost bin with plant waste rotting in it")]
+	#[assoc(builtin_grow = (2, Structure::CompostReady, None))]
+	CompostHeap,
+	
+	#[assoc(sprite = Sprite::CompostReady)]
+	#[assoc(blocking = true)]
+	#[assoc(describe = "Compost bin full of compost")]
+	#[assoc(builtin_interactions = vec![Interactable::new(InteractionType::Take, 0, &[], Some(Structure::CompostBin), &[Item::Compost])])]
+	CompostReady,
+	
 	#[assoc(sprite = Sprite::WorkTable)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Gardening worktable. Build a crude hoe with a stick, some reed rope and a sharp stone")]
@@ -250,48 +348,50 @@ pub enum Structure {
 	#[assoc(describe = "Mark stone. Center of a land claim")]
 	MarkStone,
 	
+	// The seeding plants are crop stages now. These are only kept for old saves and base maps
 	#[assoc(sprite = Sprite::SeedingHardPlant)]
 	#[assoc(describe = "Seeding Hardwood")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::BrownSeed, Item::BrownSeed])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::BrownSeed, Item::BrownSeed])])]
 	SeedingHardPlant,
 	
 	#[assoc(sprite = Sprite::SeedingDiscPlant)]
 	#[assoc(describe = "Seeding Disc plant")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::GreenSeed, Item::GreenSeed])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::GreenSeed, Item::GreenSeed])])]
 	SeedingDiscPlant,
 	
 	#[assoc(sprite = Sprite::SeedingKnifePlant)]
 	#[assoc(describe = "Seeding Knife plant")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::YellowSeed, Item::YellowSeed])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::YellowSeed, Item::YellowSeed])])]
 	SeedingKnifePlant,
 	
 	#[assoc(sprite = Sprite::DiscLeaf)]
 	#[assoc(describe = "DiscLeaf")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::DiscLeaf])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::DiscLeaf])])]
 	DiscLeaf,
 	
 	#[assoc(sprite = Sprite::KnifeLeaf)]
 	#[assoc(describe = "KnifeLeaf")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::KnifeLeaf])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::KnifeLeaf])])]
 	KnifeLeaf,
 	
 	#[assoc(sprite = Sprite::HardwoodStick)]
 	#[assoc(describe = "Hardwood stick")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::HardwoodStick])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::HardwoodStick])])]
 	HardwoodStick,
 	
 	#[assoc(sprite = Sprite::HardwoodKnife)]
 	#[assoc(describe = "Hardwood knife")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::HardwoodKnife])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::HardwoodKnife])])]
 	HardwoodKnife,
 	
 	#[assoc(sprite = Sprite::HardwoodTable)]
 	#[assoc(describe = "Hardwood Table. Can be used for crafting")]
-	#[assoc(interactions = vec![
+	#[assoc(builtin_interactions = vec![
 		Interactable::take(&[Item::HardwoodTable]),
 		Interactable::transform(InteractionType::BuildSaw, 1, Structure::SawTable)
 	])]
 	#[assoc(craft = CraftType::GardeningTable)]
+	#[assoc(burn = Structure::Blaze)]
 	HardwoodTable,
 	
 	#[assoc(sprite = Sprite::SawTable)]
@@ -301,25 +401,42 @@ pub enum Structure {
 	
 	#[assoc(sprite = Sprite::SawBlade)]
 	#[assoc(describe = "Saw blade")]
-	#[assoc(interactions = vec![Interactable::take(&[Item::SawBlade])])]
+	#[assoc(builtin_interactions = vec![Interactable::take(&[Item::SawBlade])])]
 	SawBlade,
 	
 	#[assoc(sprite = Sprite::WoodWall)]
 	#[assoc(blocking = true)]
 	#[assoc(describe = "Wooden wall")]
-	#[assoc(interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Plank])])]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Plank])])]
+	#[assoc(burn = Structure::Blaze)]
 	PlankWall,
 	
+	#[assoc(sprite = Sprite::Sprinkler)]
+	#[assoc(blocking = true)]
+	#[assoc(describe = "Sprinkler. It waters the crops around it when there is water next to it")]
+	#[assoc(builtin_interactions = vec![Interactable::harvest(InteractionType::Chop, 1, &[1.0], &[Item::Sprinkler])])]
+	#[assoc(burn = Structure::Blaze)]
+	Sprinkler,
+	
 	#[assoc(sprite = _0.sprite())]
-	#[assoc(description = _0.description())]
-	#[assoc(interactions = _0.all_interactions())]
-	#[assoc(grow = _0.grow()?)]
-	#[assoc(join = _0.join(other)?)]
+	#[assoc(builtin_description = _0.description())]
+	#[assoc(builtin_interactions = _0.all_interactions())]
+	#[assoc(builtin_grow = _0.grow()?)]
+	#[assoc(join = _0.join(other, rind)?)]
+	#[assoc(growth_speed = _0.growth_speed())]
+	#[assoc(needs_soil = true)]
+	#[assoc(needs_daylight = true)]
+	#[assoc(grows_in = _0.grows_in(season))]
+	#[assoc(burn = Structure::BrushFire)]
 	Crop(Crop),
 
 	#[assoc(spawn = _0)]
 	Spawn(Npc),
 
+	#[assoc(spawn = _0)]
+	#[assoc(spawn_phase = DayPhase::Night)]
+	NightSpawn(Npc),
+
 	#[assoc(take = _0)]
 	#[assoc(describe = _0.description())]
 	#[assoc(sprite = _0.sprite()?)]
@@ -328,37 +445,73 @@ pub enum Structure {
 
 
 impl Structure {
+	/// Replace structures that have become crop stages
+	pub fn migrated(self) -> Self {
+		match self {
+			Self::SeedingHardPlant => Self::Crop(Crop::new(CropType::SeedingHardPlant)),
+			Self::SeedingDiscPlant => Self::Crop(Crop::new(CropType::SeedingDiscPlant)),
+			Self::SeedingKnifePlant => Self::Crop(Crop::new(CropType::SeedingKnifePlant)),
+			_ => self,
+		}
+	}
+
+	fn interactions(self) -> Vec<Interactable> {
+		content::structure(self)
+			.and_then(|def| def.interactions.clone())
+			.unwrap_or_else(|| self.builtin_interactions())
+	}
+
+	fn description(self) -> Option<String> {
+		content::structure(self)
+			.and_then(|def| def.description.clone())
+			.or_else(|| self.builtin_description())
+	}
+
+	fn grow(self) -> Option<(i64, Structure, Option<Structure>)> {
+		content::structure(self)
+			.and_then(|def| def.grow)
+			.or_else(|| self.builtin_grow())
+	}
+
 	fn interactables(self) -> Vec<Interactable> {
 		let mut interactions = self.interactions();
 		if let Some(item) = self.take() {
 			interactions.push(Interactable::take(&[item]));
 		}
+		if let Some(fire) = self.burn() {
+			interactions.push(Interactable::transform(InteractionType::Ignite, 1, fire));
+		}
 		interactions
 	}
 	
-	pub fn joined(self, other: Structure) -> Option<Structure> {
-		self.join(other).or_else(|| other.join(self))
+	pub fn joined(self, other: Structure, rind: u32) -> Option<Structure> {
+		self.join(other, rind).or_else(|| other.join(self, rind))
 	}
 }
 
 
+/// How fertile untouched soil is
+pub const FERTILE: u8 = 4;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Tile {
 	pub ground: Ground,
-	pub structure: Structure
+	pub structure: Structure,
+	/// Harvests wear the soil out and crops won't grow when nothing is left
+	pub fertility: u8,
 }
 
 impl Tile {
 	pub fn empty() -> Tile{
-		Self{ground: Ground::Empty, structure: Structure::Air}
+		Self{ground: Ground::Empty, structure: Structure::Air, fertility: FERTILE}
 	}
 
 	pub fn ground(ground: Ground) -> Tile{
-		Self{ground, structure: Structure::Air}
+		Self{ground, structure: Structure::Air, fertility: FERTILE}
 	}
 	
 	pub fn structure(ground: Ground, structure: Structure) -> Tile {
-		Self{ground, structure}
+		Self{ground, structure, fertility: FERTILE}
 	}
 	
 	pub fn view(self) -> TileView {
@@ -372,30 +525,41 @@ impl Tile {
 		!self.ground.accessible() || self.structure.blocking()
 	}
 	
+	pub fn has_station(self, station: CraftType) -> bool {
+		Some(station) == self.structure.craft() || Some(station) == self.ground.craft()
+	}
+	
 	fn can_build(self) -> bool {
 		self.structure.is_open() && self.ground.buildable()
 	}
 
 	pub fn inspect(self) -> String {
 		let mut text = self.ground.describe().unwrap_or("").to_string();
+		if self.fertility == 0 {
+			text = format!("{} (exhausted)", text);
+		} else if self.fertility < FERTILE {
+			text = format!("{} (tired)", text);
+		}
 		if let Some(s) = self.structure.description() {
 			text = format!("{} | {}", text, s);
 		}
 		text
 	}
 	
-	pub fn interact(self, item: Item, time: Timestamp) -> Option<InteractionResult> {
-		item.actions().into_iter().find_map(|action| self.act(action, item, time))
+	pub fn interact(self, item: Item, rind: u32) -> Option<InteractionResult> {
+		item.actions().into_iter().find_map(|action| self.act(action, item, rind))
 	}
 	
-	pub fn act(self, action: Action, item: Item, time: Timestamp) -> Option<InteractionResult> {
+	pub fn act(self, action: Action, item: Item, rind: u32) -> Option<InteractionResult> {
 		match action {
 			Action::Interact(interact) => {
 				let mut result = self.structure.interactables()
 					.into_iter()
-					.find_map(|interactable| interactable.apply(interact, time))?;
+					.find_map(|interactable| interactable.apply(interact, rind))?;
 				if interact.use_item {
 					result.cost.insert(item, 1);
+				} else {
+					result.wear = true;
 				}
 				Some(result)
 			}
@@ -403,6 +567,27 @@ impl Tile {
 				if self.structure.is_open() {
 					Some(InteractionResult {
 						remains_ground: Some(self.ground.clear()?),
+						wear: true,
+						..Default::default()
+					})
+				} else {
+					None
+				}
+			Action::Dig =>
+				if self.structure.is_open() {
+					Some(InteractionResult {
+						remains_ground: Some(self.ground.dig()?),
+						wear: true,
+						..Default::default()
+					})
+				} else {
+					None
+				}
+			Action::Enrich =>
+				if self.fertility < FERTILE {
+					Some(InteractionResult {
+						fertility: Some(FERTILE),
+						cost: hashmap!{item => 1},
 						..Default::default()
 					})
 				} else {
@@ -433,10 +618,11 @@ impl Tile {
 				}
 			Action::Craft(typ, product, mut cost) => {
 				cost.entry(item).and_modify(|n| {*n += 1;}).or_insert(1);
-				if Some(typ) == self.structure.craft() || Some(typ) == self.ground.craft() {
+				if self.has_station(typ) {
 					Some(InteractionResult {
 						items: vec![product],
 						cost,
+						recipe: Some(RecipeId { station: typ, product }),
 						..Default::default()
 					})
 				} else {
@@ -447,15 +633,42 @@ impl Tile {
 	}
 	
 	pub fn grow(self) -> Option<(i64, Structure, Option<Structure>)> {
+		if self.fertility == 0 && self.structure.needs_soil() {
+			return None;
+		}
 		self.structure.grow()
 	}
 
-	pub fn spawn(self) -> Option<Npc> {
+	pub fn spawn(self, phase: DayPhase) -> Option<Npc> {
 		self.structure.spawn()
+			.filter(|_| self.structure.spawn_phase().is_none_or(|spawn_phase| spawn_phase == phase))
+	}
+
+	/// Whether the structure on this tile can grow during the season
+	pub fn grows_in(self, season: Season) -> bool {
+		self.structure.grows_in(season)
+	}
+
+	/// Whether this tile only changes while the sun is up
+	pub fn needs_daylight(self) -> bool {
+		self.structure.needs_daylight()
+	}
+
+	/// How much faster than normal the structure on this tile grows
+	pub fn growth_speed(self) -> i64 {
+		self.structure.growth_speed()
+	}
+
+	/// The tile after it catches fire, if it can burn
+	pub fn ignite(self) -> Option<Tile> {
+		if self.ground.has_water() {
+			return None;
+		}
+		Some(Self { structure: self.structure.burn()?, ..self })
 	}
 
 	pub fn take(self) -> Option<(Tile, Item)> {
-		Some((Self::ground(self.ground), self.structure.take()?))
+		Some((Self { structure: Structure::Air, ..self }, self.structure.take()?))
 	}
 }
 
@@ -468,14 +681,26 @@ impl Default for Tile {
 impl Serialize for Tile {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where S: Serializer {
-		(self.ground, self.structure).serialize(serializer)
+		if self.fertility == FERTILE {
+			(self.ground, self.structure).serialize(serializer)
+		} else {
+			(self.ground, self.structure, self.fertility).serialize(serializer)
+		}
 	}
 }
 impl<'de> Deserialize<'de> for Tile {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		let (ground, structure) = <(Ground, Structure)>::deserialize(deserializer)?;
-		Ok(Self{ground, structure})
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum SavedTile {
+			Fertile(Ground, Structure),
+			Worn(Ground, Structure, u8),
+		}
+		Ok(match SavedTile::deserialize(deserializer)? {
+			SavedTile::Fertile(ground, structure) => Self::structure(ground, structure),
+			SavedTile::Worn(ground, structure, fertility) => Self{ground, structure, fertility},
+		})
 	}
 }
 
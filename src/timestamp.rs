@@ -3,6 +3,34 @@ use std::ops::{Add, Sub};
 use serde::{Serialize, Deserialize};
 use crate::random;
 
+/// The default length of a full day and night
+pub const DEFAULT_DAY_LENGTH: Duration = Duration(6000);
+/// A day needs at least one step of daylight and one of night
+pub const MIN_DAY_LENGTH: Duration = Duration(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum DayPhase {
+	Day,
+	Night,
+}
+
+/// Seasons have a fixed length that doesn't depend on the configured day length
+pub const SEASON_LENGTH: Duration = Duration(DEFAULT_DAY_LENGTH.0 * 7);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum Season {
+	Spring,
+	Summer,
+	Autumn,
+	Winter,
+}
+
+impl Season {
+	pub const ALL: [Season; 4] = [Season::Spring, Season::Summer, Season::Autumn, Season::Winter];
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Timestamp(pub i64);
 
@@ -18,6 +46,45 @@ impl Timestamp {
 	pub fn random_seed(self) -> u32 {
 		random::randomize_u32(self.0 as u32 ^ 12345)
 	}
+
+	/// The first half of each day is daylight, the second half is night
+	pub fn day_phase(self, day_length: Duration) -> DayPhase {
+		if self.0.rem_euclid(day_length.0) < day_length.0 / 2 {
+			DayPhase::Day
+		} else {
+			DayPhase::Night
+		}
+	}
+
+	pub fn season(self) -> Season {
+		Season::ALL[self.0.div_euclid(SEASON_LENGTH.0).rem_euclid(4) as usize]
+	}
+
+	/// The moment the season after the current one starts
+	pub fn next_season(self) -> Self {
+		Self((self.0.div_euclid(SEASON_LENGTH.0) + 1) * SEASON_LENGTH.0)
+	}
+
+	/// The moment at which `duration` of daylight has passed since this timestamp.
+	/// Nights are skipped
+	pub fn after_daylight(self, duration: Duration, day_length: Duration) -> Self {
+		let daylight = day_length.0 / 2;
+		let mut time = self.0;
+		let mut remaining = duration.0;
+		while remaining > 0 {
+			let day_start = time - time.rem_euclid(day_length.0);
+			let into_day = time - day_start;
+			if into_day < daylight {
+				let left = daylight - into_day;
+				if remaining <= left {
+					return Self(time + remaining);
+				}
+				remaining -= left;
+			}
+			time = day_start + day_length.0;
+		}
+		Self(time)
+	}
 }
 
 impl Add<Duration> for Timestamp {
@@ -56,3 +123,29 @@ impl Add<Self> for Duration {
 		Duration(self.0 + other.0)
 	}
 }
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn daylight_skips_nights() {
+		let day_length = Duration(100);
+		assert_eq!(Timestamp(10).after_daylight(Duration(20), day_length), Timestamp(30));
+		assert_eq!(Timestamp(40).after_daylight(Duration(20), day_length), Timestamp(110));
+		assert_eq!(Timestamp(70).after_daylight(Duration(20), day_length), Timestamp(120));
+		assert_eq!(Timestamp(10).after_daylight(Duration(150), day_length), Timestamp(310));
+		assert_eq!(Timestamp(70).day_phase(day_length), DayPhase::Night);
+		assert_eq!(Timestamp(-20).day_phase(day_length), DayPhase::Night);
+	}
+
+	#[test]
+	fn seasons_cycle() {
+		assert_eq!(Timestamp(0).season(), Season::Spring);
+		assert_eq!(Timestamp(SEASON_LENGTH.0 - 1).next_season().season(), Season::Summer);
+		assert_eq!(Timestamp(SEASON_LENGTH.0 * 3).season(), Season::Winter);
+		assert_eq!(Timestamp(SEASON_LENGTH.0 * 3).next_season().season(), Season::Spring);
+		assert_eq!(Timestamp(-1).season(), Season::Winter);
+	}
+}
@@ -0,0 +1,63 @@
+
+use serde::{Serialize, Deserialize};
+use enum_assoc::Assoc;
+use crate::{
+	pos::Pos,
+	timestamp::{Timestamp, Duration},
+	random::{WhiteNoise, pick_weighted},
+};
+
+/// The weather is the same everywhere within a square region of this size
+const REGION_SIZE: i32 = 64;
+/// How long the weather stays the same in a region
+const SPELL_LENGTH: Duration = Duration(1200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Assoc, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+#[func(pub fn waters(self) -> bool {false})]
+#[func(pub fn dries(self) -> bool {false})]
+pub enum Weather {
+	Clear,
+	#[assoc(waters = true)]
+	Rain,
+	#[assoc(dries = true)]
+	Drought,
+	#[assoc(waters = true)]
+	Storm,
+}
+
+impl Weather {
+	/// The weather at a position. It only depends on the seed, region and time, so it never has to be stored
+	pub fn at(seed: u32, pos: Pos, time: Timestamp) -> Self {
+		let region = Pos::new(pos.x.div_euclid(REGION_SIZE), pos.y.div_euclid(REGION_SIZE));
+		let spell = time.0.div_euclid(SPELL_LENGTH.0) as u32;
+		let rind = WhiteNoise::new(seed.wrapping_add(spell.wrapping_mul(7793))).gen(region);
+		*pick_weighted(rind, &[
+			(Self::Clear, 12),
+			(Self::Rain, 5),
+			(Self::Drought, 2),
+			(Self::Storm, 1),
+		])
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn weather_is_regional() {
+		let time = Timestamp(4000);
+		assert_eq!(Weather::at(3, Pos::new(1, 1), time), Weather::at(3, Pos::new(63, 40), time));
+		assert_eq!(Weather::at(3, Pos::new(1, 1), time), Weather::at(3, Pos::new(1, 1), time + Duration(10)));
+	}
+
+	#[test]
+	fn all_weather_happens() {
+		let seen: Vec<Weather> = (0..200).map(|x| Weather::at(8, Pos::new(x * REGION_SIZE, 0), Timestamp(0))).collect();
+		for weather in [Weather::Clear, Weather::Rain, Weather::Drought, Weather::Storm] {
+			assert!(seen.contains(&weather), "{:?} never happens", weather);
+		}
+	}
+}
@@ -5,27 +5,56 @@ use serde::{Serialize, Deserialize};
 use crate::{
 	player::{PlayerId, PlayerConfigMsg},
 	config::MapDef,
-	controls::{Plan, Control},
-	pos::{Pos, Direction},
+	controls::{Plan, Control, RecipeQuery, ClaimChange},
+	pos::{Pos, Direction, Area},
 	worldmessages::{WorldMessage, ViewAreaMessage, ChangeMessage, SoundType::{BuildError}, SoundType},
-	timestamp::{Timestamp},
-	creature::{PlayerSave, CreatureView},
-	creatures::{Creatures, CreatureId, PlayerNotFound, PlayerAlreadyExists, CreatureNotFound},
+	timestamp::{Timestamp, Duration, DEFAULT_DAY_LENGTH},
+	creature::{PlayerSave, NpcSave, CreatureView, PvpPolicy},
+	creatures::{Creatures, CreatureId, SpawnId, PlayerNotFound, PlayerAlreadyExists, CreatureNotFound},
 	map::{Map, MapSave},
 	basemap::BaseMapImpl,
 	loadedareas::LoadedAreas,
 	item::Item,
 	creaturemap::{CreatureMap, CreatureTile},
+	recipe::Recipe,
+	inventory::ItemData,
+	tile::Structure,
+	equipment::Slot,
+	pathfinding,
+	random,
 };
 
+const MAX_GOTO_DISTANCE: i32 = 64;
+/// How far from their claim players can build
+const CLAIM_RADIUS: i32 = 24;
+const BURN_DAMAGE: i32 = 3;
+/// How often creatures standing in a fire get hurt
+const BURN_INTERVAL: Duration = Duration(10);
+/// How close two fed animals have to be to breed
+const BREEDING_DISTANCE: i32 = 2;
+
 pub struct World {
 	pub name: String,
 	pub time: Timestamp,
 	ground: Map,
 	creatures: Creatures,
 	claims: HashMap<PlayerId, Pos>,
+	claim_settings: HashMap<PlayerId, ClaimSettings>,
 	mapdef: MapDef,
 	loaded_areas: LoadedAreas,
+	pub pvp: PvpPolicy,
+}
+
+/// What the owner of a claim allows on their land
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimSettings {
+	#[serde(default)]
+	pub allow_fire: bool,
+}
+
+/// The land that belongs to a claim
+fn claim_area(pos: Pos) -> Area {
+	Area::centered(pos, Pos::new(CLAIM_RADIUS * 2 + 1, CLAIM_RADIUS * 2 + 1))
 }
 
 impl World {
@@ -38,12 +67,22 @@ impl World {
 			ground: Map::new(basemap, time),
 			time,
 			claims: HashMap::new(),
+			claim_settings: HashMap::new(),
 			creatures: Creatures::new(),
 			mapdef,
 			loaded_areas: LoadedAreas::new(),
+			pvp: PvpPolicy::default(),
 		}
 	}
 	
+	pub fn day_length(&self) -> Duration {
+		self.ground.day_length()
+	}
+
+	pub fn set_day_length(&mut self, day_length: Duration) {
+		self.ground.set_day_length(day_length);
+	}
+
 	pub fn default_player(&mut self, name: String) -> PlayerSave {
 		PlayerSave::new(name, self.ground.player_spawn())
 	}
@@ -64,7 +103,19 @@ impl World {
 	}
 	
 	pub fn control_player(&mut self, playerid: &PlayerId, control: Control) -> Result<(), CreatureNotFound> {
-		self.creatures.get_player_mut(playerid).ok_or(CreatureNotFound(CreatureId::Player(*playerid)))?.control(control);
+		let mut player = self.creatures.get_player_mut(playerid).ok_or(CreatureNotFound(CreatureId::Player(*playerid)))?;
+		match control {
+			Control::Plan(plan) => player.set_plan(plan),
+			Control::Direct(change) => player.change(change),
+			Control::Claim(_) if !self.claims.contains_key(playerid) => {
+				player.hear(BuildError, "You don't have a land claim".to_string());
+			}
+			Control::Claim(ClaimChange::AllowFire(allow)) => {
+				self.claim_settings.entry(*playerid).or_default().allow_fire = allow;
+				let areas = self.fireproof_areas();
+				self.ground.set_fireproof(areas);
+			}
+		}
 		Ok(())
 	}
 	
@@ -77,7 +128,8 @@ impl World {
 		let mut creature_map = CreatureMap::new(self.creatures.all());
 		for mut creature in self.creatures.all_mut() {
 			if creature.can_act(self.time) {
-				creature.plan(&creature_map, &self.ground, self.time);
+				let pen = creature.owner().and_then(|owner| self.claims.get(&owner)).map(|pos| claim_area(*pos));
+				creature.plan(&creature_map, &self.ground, self.time, self.pvp, pen);
 			}
 		}
 		let creatures: Vec<CreatureId> = self.creatures.all().map(|creature| creature.id).collect();
@@ -97,7 +149,8 @@ impl World {
 					let mut creature = self.creatures.get_creature_mut(&id).unwrap();
 					let newpos = creature.pos + direction;
 					let tile = self.ground.cell(newpos);
-					if !tile.blocking() && !creature_map.blocking(newpos, &CreatureTile::new(&creature)) {
+					let shunned = creature.faction().is_hostile() && tile.ground.safe();
+					if !tile.blocking() && !shunned && !creature_map.blocking(newpos, &CreatureTile::new(&creature)) {
 						creature_map.move_creature(&creature, &creature.pos, newpos);
 						creature.walk_to(newpos, self.time);
 					} else {
@@ -118,6 +171,25 @@ impl World {
 						}
 					}
 				}
+				Plan::GoTo(destination) => {
+					let mut creature = self.creatures.get_creature_mut(&id).unwrap();
+					let ct = CreatureTile::new(&creature);
+					let walkable = |p| !self.ground.cell(p).blocking() && !creature_map.blocking(p, &ct);
+					let path = Some(destination)
+						.filter(|destination| creature.pos.distance_to(*destination) <= MAX_GOTO_DISTANCE)
+						.and_then(|destination| pathfinding::find_path(creature.pos, destination, pathfinding::PLAYER_BUDGET, walkable));
+					if let Some(path) = path {
+						creature.path = path;
+					} else {
+						creature.hear(SoundType::Explain, "Can't find a way there".to_string());
+					}
+				}
+				Plan::Equip(index) => {
+					self.creatures.get_creature_mut(&id).unwrap().equip(index);
+				}
+				Plan::Unequip(slot) => {
+					self.creatures.get_creature_mut(&id).unwrap().unequip(slot);
+				}
 				Plan::Inspect(pos) => {
 					let mut creature = self.creatures.get_creature_mut(&id).unwrap();
 					if pos.distance_to(creature.pos) < 32 {
@@ -134,21 +206,40 @@ impl World {
 				}
 				Plan::InspectItem(index) => {
 					let mut creature = self.creatures.get_creature_mut(&id).unwrap();
-					if let Some(item) = creature.inventory.get_item(index) {
-						creature.hear(SoundType::Explain, item.description().to_string());
+					if let Some(description) = creature.inventory.describe_item(index) {
+						creature.hear(SoundType::Explain, description);
 					}
 				}
 				Plan::Take(direction) => {
 					self.take(&id, direction);
 				}
+				Plan::Craft(direction, product, quantity) => {
+					self.craft(&id, direction, product, quantity);
+				}
+				Plan::Recipes(query) => {
+					let mut creature = self.creatures.get_creature_mut(&id).unwrap();
+					let item = match query {
+						RecipeQuery::Item(index) => creature.inventory.get_item(index),
+						RecipeQuery::Station(_) => None,
+					};
+					let recipes = Recipe::all()
+						.into_iter()
+						.filter(|recipe| match query {
+							RecipeQuery::Station(station) => recipe.id.station == station,
+							RecipeQuery::Item(_) => item.is_some_and(|item| recipe.uses(item)),
+						});
+					creature.look_up_recipes(recipes);
+				}
 				Plan::Use(index, direction) => {
-					self.use_item(&id, index, direction);
+					self.feed(&id, index, direction)
+						.or_else(|| self.use_item(&id, index, direction));
 				}
 				Plan::Fight(direction) => {
 					self.fight(&id, direction, &creature_map);
 				}
 				Plan::Interact(Some(index), direction) => {
-					self.fight(&id, direction, &creature_map)
+					self.feed(&id, index, direction)
+						.or_else(|| self.fight(&id, direction, &creature_map))
 						.or_else(|| self.use_item(&id, index, direction))
 						.or_else(|| self.take(&id, direction));
 				}
@@ -159,41 +250,231 @@ impl World {
 			}
 		}
 
+		let mut deaths = Vec::new();
+		let mut knockouts = Vec::new();
 		for mut creature in self.creatures.all_mut() {
-			creature.update(self.time);
+			if self.time.0 % BURN_INTERVAL.0 == 0 && self.ground.cell(creature.pos).structure.burning() {
+				creature.burn(BURN_DAMAGE, self.time);
+			}
+			let sheltered = self.ground.cell(creature.pos).ground.safe();
+			if creature.update(self.time, sheltered) {
+				deaths.push(creature.id);
+			}
+			// only players beaten by other players are sent back to the spawn
+			if creature.is_knocked_out() && creature.killer().is_some_and(|killer| killer.player().is_some()) {
+				knockouts.push(creature.id);
+			}
+			if self.ground.cell(creature.pos).structure.is_open() {
+				if let Some(item) = creature.produce(self.time) {
+					self.ground.set_structure(creature.pos, Structure::Item(item));
+				}
+			}
+		}
+		for id in deaths {
+			self.drop_loot(&id);
+		}
+		for id in knockouts {
+			self.respawn(&id);
+		}
+	}
+
+	fn respawn(&mut self, id: &CreatureId) {
+		let (name, killer) = {
+			let creature = self.creatures.get_creature(id).unwrap();
+			(creature.name.clone(), creature.killer())
+		};
+		let message = match killer.and_then(|killer| self.creatures.get_creature(&killer)) {
+			Some(killer) => format!("{} killed {}", killer.name, name),
+			None => format!("{} was killed", name),
+		};
+		self.creatures.get_creature_mut(id).unwrap().respawn(self.ground.player_spawn());
+		self.broadcast(SoundType::Kill, message);
+	}
+
+	fn drop_loot(&mut self, id: &CreatureId) {
+		let (pos, killer, loot) = {
+			let creature = self.creatures.get_creature(id).unwrap();
+			(creature.pos, creature.killer(), creature.roll_loot(self.time))
+		};
+		let mut killer = killer.and_then(|killer_id| self.creatures.get_creature_mut(&killer_id));
+		let mut free_spots = [pos].into_iter()
+			.chain(Direction::DIRECTIONS.map(|direction| pos + direction))
+			.filter(|p| self.ground.cell(*p).structure.is_open())
+			.collect::<Vec<Pos>>()
+			.into_iter();
+		for drop in loot {
+			if drop.to_killer && killer.as_mut().is_some_and(|killer| killer.inventory.add(drop.item)) {
+				continue;
+			}
+			if let Some(spot) = free_spots.next() {
+				self.ground.set_structure(spot, Structure::Item(drop.item));
+			}
 		}
 	}
 	
 	fn fight(&mut self, id: &CreatureId, direction: Option<Direction>, creature_map: &CreatureMap) -> Option<()> {
 		let mut creature = self.creatures.get_creature_mut(id).unwrap();
-		let pos = creature.pos + direction;
-		let opponent = creature_map.get(&pos).iter().find(|o| creature.faction().is_enemy(o.faction))?.id;
-		creature.attack(self.creatures.get_creature_mut(&opponent).unwrap(), self.time);
+		if self.ground.cell(creature.pos).ground.safe() {
+			return None;
+		}
+		let me = CreatureTile::new(&creature);
+		let mut pos = creature.pos;
+		let mut opponent = None;
+		for _ in 0..creature.combat_stats().reach {
+			pos = pos + direction;
+			opponent = creature_map.get(&pos).iter()
+				.find(|o| !self.ground.cell(o.pos).ground.safe()
+					&& o.owner.is_none_or(|owner| Some(&owner) == id.player())
+					&& (me.faction.can_attack(o.faction) || self.pvp.allows(&me, o)))
+				.map(|o| o.id);
+			if opponent.is_some() || direction.is_none() || self.ground.cell(pos).blocking() {
+				break;
+			}
+		}
+		let opponent = self.creatures.get_creature_mut(&opponent?).unwrap();
+		creature.attack(opponent, self.time);
+		let holds_weapon = creature.held_item().is_some_and(|item| item.weapon().is_some());
+		if let Some(broken) = Some(Slot::Hand).filter(|_| holds_weapon).and_then(|slot| creature.equipment.wear(slot)) {
+			creature.hear(SoundType::Explain, format!("Your {} broke", broken.name()));
+		}
 		Some(())
 	}
+
+	fn broadcast(&self, sound_type: SoundType, message: String) {
+		for player_id in self.creatures.list_players() {
+			if let Some(mut player) = self.creatures.get_player_mut(&player_id) {
+				player.hear(sound_type.clone(), message.clone());
+			}
+		}
+	}
+	
+	fn craft(&mut self, id: &CreatureId, direction: Option<Direction>, product: Item, quantity: usize) {
+		let mut creature = self.creatures.get_creature_mut(id).unwrap();
+		let pos = creature.pos + direction;
+		let tile = self.ground.cell(pos);
+		let Some(recipe) = Recipe::all()
+				.into_iter()
+				.filter(|recipe| recipe.id.product == product && tile.has_station(recipe.id.station))
+				.max_by_key(|recipe| creature.inventory.affordable_count(&recipe.ingredients)) else {
+			creature.hear(BuildError, format!("Can't craft {} here", product.name()));
+			return;
+		};
+		if !creature.inventory.can_afford(&recipe.ingredients) {
+			creature.hear(BuildError, format!("Not enough ingredients to craft {}", product.name()));
+			return;
+		}
+		let mut count = 0;
+		while count < quantity
+				&& creature.inventory.can_afford(&recipe.ingredients)
+				&& creature.inventory.fits(&recipe.ingredients, &[product]) {
+			creature.inventory.pay(recipe.ingredients.clone());
+			let rind = random::randomize_u32(random::randomize_pos(pos) ^ self.time.random_seed().wrapping_add(count as u32));
+			creature.inventory.add_instance(product, ItemData::crafted(product, rind));
+			count += 1;
+		}
+		if count == 0 {
+			creature.hear(SoundType::InventoryFull, "Inventory full".to_string());
+			return;
+		}
+		creature.learn_recipe(recipe.id);
+		creature.craft(pos, Duration(recipe.id.station.duration().0 * count as i64), self.time);
+	}
 	
 	fn use_item(&mut self, id: &CreatureId, index: usize, direction: Option<Direction>) -> Option<()> {
 		let item = self.creatures.get_creature(id).unwrap().inventory.get_item(index)?;
-		self.interact_creature(id, direction, item)
+		self.interact_creature(id, direction, item, Some(index))
+			.or_else(|| self.creatures.get_creature_mut(id).unwrap().eat(index).then_some(()))
 	}
 	
+	/// Feeding a wild animal tames it. Feeding a tame one makes it ready to breed
+	fn feed(&mut self, id: &CreatureId, index: usize, direction: Option<Direction>) -> Option<()> {
+		let player_id = *id.player()?;
+		let (pos, item) = {
+			let feeder = self.creatures.get_creature(id)?;
+			(feeder.pos + direction?, feeder.inventory.get_item(index)?)
+		};
+		let animal_id = self.creatures.all()
+			.find(|creature| creature.pos == pos && creature.kind().tame_food() == Some(item))
+			.map(|creature| creature.id)?;
+		let mut animal = self.creatures.get_creature_mut(&animal_id)?;
+		let mut feeder = self.creatures.get_creature_mut(id)?;
+		match animal.owner() {
+			Some(owner) if owner != player_id => {
+				feeder.hear(BuildError, format!("This {} belongs to someone else", animal.name));
+				return Some(());
+			}
+			Some(_) if animal.is_fed() => {
+				feeder.hear(SoundType::Explain, format!("The {} is not hungry", animal.name));
+				return Some(());
+			}
+			Some(_) => {
+				animal.set_fed(true);
+				feeder.inventory.take_one(index);
+				drop(feeder);
+				drop(animal);
+				self.breed(&animal_id);
+			}
+			None => {
+				feeder.hear(SoundType::Explain, format!("The {} now follows you", animal.name));
+				feeder.inventory.take_one(index);
+				drop(feeder);
+				drop(animal);
+				self.creatures.tame(&animal_id, player_id, self.time);
+			}
+		}
+		Some(())
+	}
+
+	/// Two fed tame animals of the same kind and owner that are close together get a young one
+	fn breed(&mut self, id: &CreatureId) {
+		let (pos, kind, owner) = {
+			let animal = self.creatures.get_creature(id).unwrap();
+			if !animal.is_fed() {
+				return;
+			}
+			let Some(owner) = animal.owner() else {
+				return;
+			};
+			(animal.pos, animal.kind(), owner)
+		};
+		let Some(partner_id) = self.creatures.all()
+				.find(|other| other.id != *id
+					&& other.kind() == kind
+					&& other.owner() == Some(owner)
+					&& other.is_fed()
+					&& other.pos.distance_to(pos) <= BREEDING_DISTANCE)
+				.map(|other| other.id) else {
+			return;
+		};
+		for parent in [id, &partner_id] {
+			self.creatures.get_creature_mut(parent).unwrap().set_fed(false);
+		}
+		self.creatures.add_offspring(pos, kind, owner, self.time);
+	}
+
 	fn take(&mut self, id: &CreatureId, direction: Option<Direction>) -> Option<()> {
 		{
 			let mut creature = self.creatures.get_creature_mut(id).unwrap();
 			let pos = creature.pos + direction;
-			if let Some(item) = self.ground.take(pos) {
-				creature.inventory.add(item);
+			if let Some((_, item)) = self.ground.cell(pos).take() {
+				if !creature.inventory.add(item) {
+					creature.hear(SoundType::InventoryFull, "Inventory full".to_string());
+					return Some(());
+				}
+				self.ground.take(pos);
 				return Some(());
 			}
 		}
-		self.interact_creature(id, direction, Item::Nothing)
+		let held = self.creatures.get_creature(id).unwrap().held_item();
+		held.and_then(|item| self.interact_creature(id, direction, item, None))
+			.or_else(|| self.interact_creature(id, direction, Item::Nothing, None))
 	}
 
-	fn interact_creature(&mut self, id: &CreatureId, direction: Option<Direction>, item: Item) -> Option<()> {
+	fn interact_creature(&mut self, id: &CreatureId, direction: Option<Direction>, item: Item, index: Option<usize>) -> Option<()> {
 		let mut creature = self.creatures.get_creature_mut(id).unwrap();
 		let pos = creature.pos + direction;
 		let tile = self.ground.cell(pos);
-		let interaction = tile.interact(item, self.time)?;
+		let interaction = tile.interact(item, self.time.random_seed() ^ random::randomize_pos(pos))?;
 		if interaction.claim {
 			if let Some(player_id) = id.player() {
 				if self.claims.contains_key(player_id) {
@@ -209,6 +490,8 @@ impl World {
 					return Some(());
 				}
 				self.claims.insert(*player_id, pos);
+				let areas = self.fireproof_areas();
+				self.ground.set_fireproof(areas);
 			} else {
 				creature.hear(
 					BuildError,
@@ -219,7 +502,7 @@ impl World {
 		}
 		if interaction.build {
 			if let Some(claim_pos) = id.player().and_then(|player_id| self.claims.get(player_id)) {
-				if pos.distance_to(*claim_pos) > 24 {
+				if pos.distance_to(*claim_pos) > CLAIM_RADIUS {
 					creature.hear(
 						BuildError,
 						"Too far from land claim to build".to_string()
@@ -234,24 +517,83 @@ impl World {
 				return Some(());
 			}
 		}
-		if !creature.inventory.pay(interaction.cost) {
+		if interaction.remains.is_some_and(|remains| remains.burning())
+				&& self.fireproof_claims().any(|(owner, area)| Some(owner) != id.player() && area.contains(pos)) {
+			creature.hear(BuildError, "This land is protected from fire".to_string());
 			return Some(());
 		}
-		for item in interaction.items {
-			creature.inventory.add(item);
+		if !creature.inventory.can_afford(&interaction.cost) {
+			return Some(());
+		}
+		// planted seeds pass their genes on to the crop
+		let seed = index.and_then(|index| Some((index, creature.inventory.get_data(index)?.genes?)));
+		let remains = match (interaction.remains, seed) {
+			(Some(Structure::Crop(crop)), Some((_, genes))) => Some(Structure::Crop(crop.with_genes(genes))),
+			(remains, _) => remains,
+		};
+		if !creature.inventory.fits(&interaction.cost, &interaction.items) {
+			creature.hear(SoundType::InventoryFull, "Inventory full".to_string());
+			return Some(());
+		}
+		let broken = match index {
+			_ if !interaction.wear => None,
+			Some(index) => creature.inventory.wear(index),
+			None if creature.held_item() == Some(item) => creature.equipment.wear(Slot::Hand),
+			None => None,
+		};
+		if let Some(broken) = broken {
+			creature.hear(SoundType::Explain, format!("Your {} broke", broken.name()));
+		}
+		if let Some((index, _)) = seed {
+			creature.inventory.pay_preferring(interaction.cost, index);
+		} else {
+			creature.inventory.pay(interaction.cost);
+		}
+		let harvested = !interaction.items.is_empty();
+		for (i, item) in interaction.items.into_iter().enumerate() {
+			let rind = self.time.random_seed() ^ random::randomize_pos(pos);
+			if interaction.recipe.is_some() {
+				creature.inventory.add_instance(item, ItemData::crafted(item, rind));
+			} else if let (Structure::Crop(crop), true) = (tile.structure, item.is_seed()) {
+				let genes = crop.genes().cross(crop.genes(), random::randomize_u32(rind.wrapping_add(i as u32)));
+				creature.inventory.add_instance(item, ItemData::seed(item, genes));
+			} else {
+				creature.inventory.add(item);
+			}
+		}
+		if let Some(recipe) = interaction.recipe {
+			creature.learn_recipe(recipe);
+			creature.craft(pos, recipe.station.duration(), self.time);
 		}
-		if let Some(remains) = interaction.remains {
+		if let Some(remains) = remains {
 			self.ground.set_structure(pos, remains);
 		}
 		if let Some(remains_ground) = interaction.remains_ground {
 			self.ground.set_ground(pos, remains_ground);
 		}
+		if let Some(fertility) = interaction.fertility {
+			self.ground.set_fertility(pos, fertility);
+		}
+		if matches!(tile.structure, Structure::Crop(_)) && harvested {
+			self.ground.exhaust(pos);
+		}
 		if let Some((message_type, message_text)) = interaction.message {
 			creature.hear(message_type, message_text);
 		}
 		Some(())
 	}
 
+	/// The land around claims whose owners don't allow fire
+	fn fireproof_claims(&self) -> impl Iterator<Item=(&PlayerId, Area)> {
+		self.claims.iter()
+			.filter(|(owner, _)| !self.claim_settings.get(owner).is_some_and(|settings| settings.allow_fire))
+			.map(|(owner, pos)| (owner, claim_area(*pos)))
+	}
+
+	fn fireproof_areas(&self) -> Vec<Area> {
+		self.fireproof_claims().map(|(_, area)| area).collect()
+	}
+
 	fn update_loaded_areas(&mut self) {
 		self.loaded_areas.update(&self.creatures);
 		for fresh_area in self.loaded_areas.all_fresh() {
@@ -262,6 +604,9 @@ impl World {
 
 	fn spawn_creatures(&mut self) {
 		for (pos, npc) in self.ground.spawns() {
+			if npc.faction().is_hostile() && self.ground.cell(pos).ground.safe() {
+				continue;
+			}
 			self.creatures.spawn(pos, npc);
 		}
 		self.creatures.despawn(&self.loaded_areas, self.time);
@@ -302,7 +647,13 @@ impl World {
 			wm.dynamics = Some(dynamics.clone());
 			wm.me = Some(body.view_ext(self.time));
 			wm.inventory = Some(body.inventory.view());
+			wm.equipment = Some(body.equipment.view());
 			wm.sounds = body.heard_sounds.clone();
+			wm.recipes = body.recipe_book.clone();
+			wm.daylight = Some(self.ground.day_phase());
+			wm.weather = Some(self.ground.weather(body.pos));
+			wm.season = Some(self.time.season());
+			wm.claim = self.claims.get(id).map(|_| self.claim_settings.get(id).cloned().unwrap_or_default());
 
 			views.insert(*id, wm);
 		}
@@ -321,20 +672,36 @@ impl World {
 			time: self.time,
 			ground: self.ground.save(),
 			claims: self.claims.clone(),
+			claim_settings: self.claim_settings.clone(),
 			mapdef: self.mapdef.clone(),
+			creatures: self.creatures.save_spawned(),
+			bred: self.creatures.bred_count(),
+			wounds: self.creatures.save_wounds(),
+			pvp: self.pvp,
+			day_length: self.ground.day_length(),
 		}
 	}
 	
 	pub fn load(save: WorldSave, basemap: BaseMapImpl) -> World {
-		World {
+		let mut creatures = Creatures::new();
+		creatures.load_spawned(save.creatures, save.bred, save.time);
+		creatures.load_wounds(save.wounds);
+		let mut ground = Map::load(save.ground, save.time, basemap);
+		ground.set_day_length(save.day_length);
+		let mut world = World {
 			name: save.name,
-			ground: Map::load(save.ground, save.time, basemap),
-			creatures: Creatures::new(),
+			ground,
+			creatures,
 			time: save.time,
 			claims: save.claims,
+			claim_settings: save.claim_settings,
 			mapdef: save.mapdef,
 			loaded_areas: LoadedAreas::new(),
-		}
+			pvp: save.pvp,
+		};
+		let areas = world.fireproof_areas();
+		world.ground.set_fireproof(areas);
+		world
 	}
 }
 
@@ -345,7 +712,22 @@ pub struct WorldSave {
 	pub time: Timestamp,
 	pub ground: MapSave,
 	pub claims: HashMap<PlayerId, Pos>,
+	#[serde(default)]
+	pub claim_settings: HashMap<PlayerId, ClaimSettings>,
 	pub mapdef: MapDef,
+	#[serde(default)]
+	pub creatures: Vec<NpcSave>,
+	/// How many creatures have been bred, so bred creatures keep unique ids
+	#[serde(default)]
+	pub bred: u64,
+	/// Spawned creatures that were unloaded while wounded and the health they had left
+	#[serde(default)]
+	pub wounds: Vec<(SpawnId, i32)>,
+	#[serde(default)]
+	pub pvp: PvpPolicy,
+	#[serde(default="default_day_length")]
+	pub day_length: Duration,
 }
+fn default_day_length() -> Duration {DEFAULT_DAY_LENGTH}
 
 
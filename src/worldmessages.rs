@@ -1,14 +1,18 @@
 
-use std::collections::HashMap;
+use std::collections::{HashMap, BTreeMap};
 use serde::Serialize;
 use crate::{
 	pos::Pos,
 	pos::Area,
 	player::PlayerId,
-	timestamp::Timestamp,
+	timestamp::{Timestamp, DayPhase, Season},
 	creature::CreatureView,
 	map::SectionView,
 	tile::TileView,
+	recipe::RecipeView,
+	equipment::Slot,
+	weather::Weather,
+	world::ClaimSettings,
 };
 
 
@@ -25,11 +29,23 @@ pub struct WorldMessage {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub inventory: Option<InventoryMessage>,
 	#[serde(skip_serializing_if = "Option::is_none")]
+	pub equipment: Option<EquipmentMessage>,
+	#[serde(skip_serializing_if = "Option::is_none")]
 	pub viewarea: Option<ViewAreaMessage>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub section: Option<SectionView>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub dynamics: Option<DynamicMessage>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub recipes: Option<RecipeBookMessage>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub daylight: Option<DayPhase>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub weather: Option<Weather>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub season: Option<Season>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub claim: Option<ClaimSettings>,
 }
 
 
@@ -42,6 +58,7 @@ macro_rules! worldmessages {
 				Self {
 					tick,
 					sounds: Vec::new(),
+					recipes: None,
 					$(
 						$name: None,
 					)*
@@ -66,11 +83,13 @@ macro_rules! worldmessages {
 	}
 }
 
-worldmessages!(me, change,  inventory, viewarea, section, dynamics);
+worldmessages!(me, change,  inventory, equipment, viewarea, section, dynamics, daylight, weather, season, claim);
 
 pub type ChangeMessage = Vec<(Pos, TileView)>;
-pub type InventoryMessage = (Vec<(String, Option<usize>)>, Option<usize>);
+pub type InventoryMessage = (Vec<(String, Option<usize>, Option<(u32, u32)>)>, Option<usize>);
+pub type EquipmentMessage = BTreeMap<Slot, (String, Option<(u32, u32)>)>;
 pub type DynamicMessage = Vec<CreatureView>;
+pub type RecipeBookMessage = Vec<RecipeView>;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct ViewAreaMessage {
@@ -81,7 +100,9 @@ pub struct ViewAreaMessage {
 #[serde(rename_all="lowercase")]
 pub enum SoundType {
 	BuildError,
-	Explain
+	Explain,
+	InventoryFull,
+	Kill,
 }
 
 
@@ -29,14 +29,14 @@ class ActionBar {
 
 		for (let i in items) {
 			let item = items[i];
-			let row = this._buildRow((i|0) + this.actions.length, item[0], item[1]);
+			let row = this._buildRow((i|0) + this.actions.length, item[0], item[1], item[2]);
 
 			table.appendChild(row);
 		}
 		this.select(Math.min(this.selector, this.actions.length + this.items.length - 1));
 	}
 
-	_buildRow(index, name, quantity) {
+	_buildRow(index, name, quantity, wear) {
 		let row = document.createElement("li");
 		row.onclick = () => this.select(index);
 		row.className = "inv-row selectable-row";
@@ -44,6 +44,9 @@ class ActionBar {
 		let nm = document.createElement("span");
 		nm.className = "inventory-name";
 		nm.innerText = name;
+		if (wear) {
+			nm.innerText += ` (${wear[0]}/${wear[1]})`;
+		}
 		row.appendChild(nm);
 
 		let am = document.createElement("span");
@@ -84,6 +87,19 @@ class ActionBar {
 		}
 	}
 
+	selectedItem() {
+		if (this.selector < this.actions.length) {
+			return null;
+		} else {
+			return this.selector - this.actions.length;
+		}
+	}
+
+	equip() {
+		let index = this.selectedItem();
+		return index === null ? null : {equip: index};
+	}
+
 	inspect() {
 		if (this.selector < this.actions.length) {
 			return null;
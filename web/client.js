@@ -19,7 +19,6 @@ class Client {
 		this.map = new GameMap();
 		this.readyToDraw = false;
 		this.actionBar = new ActionBar();
-		this.path = null;
 	}
 
 	start(){
@@ -44,13 +43,15 @@ class Client {
 			ArrowLeft: () => this.startMoving(WEST),
 			KeyD: () => this.startMoving(EAST),
 			ArrowRight: () => this.startMoving(EAST),
-			Period: () => this.actionBar.selectRel(1),
-			Comma: () => this.actionBar.selectRel(-1),
-			NumpadAdd: () => this.actionBar.selectRel(1),
-			NumpadSubtract: () => this.actionBar.selectRel(-1),
-			Equal: () => this.actionBar.selectRel(1),
-			Minus: () => this.actionBar.selectRel(-1),
+			Period: () => this.selectRel(1),
+			Comma: () => this.selectRel(-1),
+			NumpadAdd: () => this.selectRel(1),
+			NumpadSubtract: () => this.selectRel(-1),
+			Equal: () => this.selectRel(1),
+			Minus: () => this.selectRel(-1),
 			KeyI: () => this.sendInput(this.actionBar.inspect()),
+			KeyE: () => this.sendInput(this.actionBar.equip()),
+			KeyU: () => this.sendInput({unequip: "hand"}),
 		};
 		let shiftKeymap = {
 			KeyW: () => this.act(NORTH),
@@ -94,10 +95,7 @@ class Client {
 		}
 		document.getElementById("canvases").addEventListener("click", e => {
 			let to = this.display.screenToWorld(vec2(e.clientX, e.clientY)).floor();
-			this.path = this.map.jumpPath(this.model.me.pos, to);
-			if (this.path !== null) {
-				this.send({input: {path: this.path.map(pos => pos.arr())}});
-			}
+			this.send({input: {goto: to.arr()}});
 		});
 		document.addEventListener("keyup", e => {
 			if (document.activeElement.classList.contains("captureinput")){
@@ -110,6 +108,12 @@ class Client {
 				}
 			}
 		});
+		document.getElementById("pvp-toggle").addEventListener("change", e => {
+			this.send({input: {pvp: e.target.checked}});
+		});
+		document.getElementById("allowfire-toggle").addEventListener("change", e => {
+			this.send({input: {allowfire: e.target.checked}});
+		});
 		document.getElementById("control-up").addEventListener("click", e => {
 			this.moveOnce(NORTH);
 		});
@@ -167,6 +171,11 @@ class Client {
 		this.sendInput({"movement": null});
 	}
 
+	selectRel(dif) {
+		this.actionBar.selectRel(dif);
+		this.send({input: {select: this.actionBar.selectedItem()}});
+	}
+
 	act(direction) {
 		this.sendInput(this.actionBar.selectedAction(direction, this.model.me.pos));
 	}
@@ -175,7 +184,6 @@ class Client {
 		if (!input) {
 			return;
 		}
-		this.path = null;
 		this.send({input: input});
 	}
 
@@ -204,24 +212,36 @@ class Client {
 		this.model.setTime(m.t);
 		if (m.me) {
 			this.model.setMe(m.me);
-			if (this.path && m.me.a && m.me.a.M) {
-				let i = this.path.findIndex(p => this.model.me.pos.equals(p));
-				if (i >= 0) {
-					while (i -->= 0) {
-						this.path.shift();
-					}
-					this.send({input: {path: this.path.map(pos => pos.arr())}});
-				}
-			} else {
-				this.path = null;
-			}
 			document.getElementById("coordinates").textContent = `${m.me.p[0]}, ${m.me.p[1]}`;
 			document.getElementById("healthtext").textContent = `${m.me.h[0]}/${m.me.h[1]}`;
 			document.getElementById("healthvalue").style.width = `${m.me.h[0]/m.me.h[1]*100}%`;
+			if (m.me.f !== undefined) {
+				document.getElementById("pvp-toggle").checked = m.me.f;
+			}
+			if (m.me.e) {
+				document.getElementById("energytext").textContent = `${m.me.e[0]}/${m.me.e[1]}`;
+				document.getElementById("energyvalue").style.width = `${m.me.e[0]/m.me.e[1]*100}%`;
+			}
+		}
+		if (m.daylight) {
+			document.getElementById("canvases").classList.toggle("night", m.daylight === "night");
+		}
+		if (m.season) {
+			document.getElementById("season").textContent = m.season;
+		}
+		if (m.claim) {
+			document.getElementById("claim-settings").hidden = false;
+			document.getElementById("allowfire-toggle").checked = m.claim.allow_fire;
+		}
+		if (m.weather) {
+			document.getElementById("canvases").dataset.weather = m.weather;
 		}
 		if (m.inventory) {
 			this.actionBar.setInventory(m.inventory[0]);
 		}
+		if (m.equipment) {
+			this.setEquipment(m.equipment);
+		}
 		if (m.sounds) {
 			for (let sound of m.sounds) {
 				this.print(sound[1], sound[0]);
@@ -247,6 +267,20 @@ class Client {
 		}
 	}
 
+	setEquipment(equipment) {
+		let list = document.getElementById("equipment");
+		list.replaceChildren();
+		for (let slot in equipment) {
+			let [name, wear] = equipment[slot];
+			let row = document.createElement("li");
+			row.className = "inv-row";
+			row.title = "click to unequip";
+			row.innerText = `${slot}: ${name}` + (wear ? ` (${wear[0]}/${wear[1]})` : "");
+			row.onclick = () => this.sendInput({unequip: slot});
+			list.appendChild(row);
+		}
+	}
+
 	sendRaw(msg) {
 		if (this.websocket.readyState === WebSocket.OPEN){
 			this.websocket.send(msg);
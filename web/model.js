@@ -39,6 +39,8 @@ class Activity {
 			return new FightActivity(a.s, a.e, vec2(...a.F.t));
 		} else if (a.D) {
 			return new DieActivity(a.s, a.e);
+		} else if (a.C) {
+			return new Activity(a.s, a.e);
 		} else {
 			console.error("Unknown activity", a);
 			return null;
@@ -1,101 +0,0 @@
-"use strict";
-
-class PriorityQueue {
-    constructor(keyfn) {
-        this.keyfn = keyfn;
-        this.heap = [];
-    }
-
-    // Helper Methods
-    getLeftChildIndex(parentIndex) {
-        return 2 * parentIndex + 1;
-    }
-
-    getRightChildIndex(parentIndex) {
-        return 2 * parentIndex + 2;
-    }
-
-    getParentIndex(childIndex) {
-        return Math.floor((childIndex - 1) / 2);
-    }
-
-    hasLeftChild(index) {
-        return this.getLeftChildIndex(index) < this.heap.length;
-    }
-
-    hasRightChild(index) {
-        return this.getRightChildIndex(index) < this.heap.length;
-    }
-
-    hasParent(index) {
-        return this.getParentIndex(index) >= 0;
-    }
-
-    leftChild(index) {
-        return this.heap[this.getLeftChildIndex(index)];
-    }
-
-    rightChild(index) {
-        return this.heap[this.getRightChildIndex(index)];
-    }
-
-    parent(index) {
-        return this.heap[this.getParentIndex(index)];
-    }
-
-    swap(indexOne, indexTwo) {
-        const temp = this.heap[indexOne];
-        this.heap[indexOne] = this.heap[indexTwo];
-        this.heap[indexTwo] = temp;
-    }
-
-    peek() {
-        if (this.heap.length === 0) {
-            return null;
-        }
-        return this.heap[0];
-    }
-
-    // Removing an element will remove the
-    // top element with highest priority then
-    // heapifyDown will be called
-    remove() {
-        if (this.heap.length === 0) {
-            return null;
-        }
-        const item = this.heap[0];
-        this.heap[0] = this.heap[this.heap.length - 1];
-        this.heap.pop();
-        this.heapifyDown();
-        return item;
-    }
-
-    add(item) {
-        this.heap.push(item);
-        this.heapifyUp();
-    }
-
-    heapifyUp() {
-        let index = this.heap.length - 1;
-        while (this.hasParent(index) && this.keyfn(this.parent(index)) > this.keyfn(this.heap[index])) {
-            this.swap(this.getParentIndex(index), index);
-            index = this.getParentIndex(index);
-        }
-    }
-
-    heapifyDown() {
-        let index = 0;
-        while (this.hasLeftChild(index)) {
-            let smallerChildIndex = this.getLeftChildIndex(index);
-            if (this.hasRightChild(index) && this.keyfn(this.rightChild(index)) < this.keyfn(this.leftChild(index))) {
-                smallerChildIndex = this.getRightChildIndex(index);
-            }
-            if (this.keyfn(this.heap[index]) < this.keyfn(this.heap[smallerChildIndex])) {
-                break;
-            } else {
-                this.swap(index, smallerChildIndex);
-            }
-            index = smallerChildIndex;
-        }
-    }
-}
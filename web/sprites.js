@@ -12,6 +12,8 @@ function loadSprites(){
 			sage: {x: 1, y: 0},
 			frog: {x: 3, y: 0, layer: "creatures"},
 			worm: {x: 4, y: 0, layer: "creatures"},
+			sheep: {x: 5, y: 0, layer: "creatures"},
+			hen: {x: 6, y: 0, layer: "creatures"},
 			fireplace: {x: 2, y: 7},
 			ashplace: {x: 3, y: 7},
 			fire: {x: 4, y: 7},
@@ -27,6 +29,13 @@ function loadSprites(){
 			moss: {x: 6, y: 1, layer: "ground"},
 			water: {x: 0, y: 2, border: 0x222266, layer: "base"},
 			stonefloor: {x: 1, y: 2, border: 0xaaaaaa, layer: "base"},
+			sanctuary: {x: 1, y: 2, border: 0xccbb77, layer: "base"},
+			channel: {x: 3, y: 1, border: 0x553311, layer: "ground"},
+			channelwater: {x: 0, y: 2, border: 0x553311, layer: "base"},
+			sprinkler: {x: 5, y: 4},
+			compostbin: {x: 0, y: 12},
+			compostheap: {x: 1, y: 12},
+			compostready: {x: 2, y: 12},
 			woodwall: {x: 3, y: 2, border: 0x222200, layer: "base"},
 			wall: {x: 4, y: 2, border: 0x222222, layer: "base"},
 			rock: {x: 5, y: 2, border: 0x222222, layer: "base"},
@@ -48,6 +57,8 @@ function loadSprites(){
 			pebble: {x: 0, y: 6},
 			stone: {x: 1, y: 6},
 			stick: {x: 2, y: 6},
+			slime: {x: 3, y: 6},
+			hide: {x: 4, y: 6},
 
 			plantedseed: {x: 0, y: 8},
 			seedling: {x: 1, y: 8},